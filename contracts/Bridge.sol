@@ -1,10 +1,17 @@
 // SPDX-License-Identifier: GPL-3.0
-pragma solidity >=0.8.0 <0.9.0;
+pragma solidity ^0.8.21;
 
+import {BridgeAdminLib} from "./BridgeAdminLib.sol";
 import {TokenManager} from "./TokenManager.sol";
+import {IBridgeAdmin} from "./interfaces/IBridgeAdmin.sol";
+import {IBridgeMintHook} from "./interfaces/IBridgeMintHook.sol";
 import {Ownable} from "@openzeppelin/contracts/access/Ownable.sol";
 import {Pausable} from "@openzeppelin/contracts/security/Pausable.sol";
 import {IERC20} from "@openzeppelin/contracts/token/ERC20/IERC20.sol";
+import {IERC20Metadata} from "@openzeppelin/contracts/token/ERC20/extensions/IERC20Metadata.sol";
+import {ECDSA} from "@openzeppelin/contracts/utils/cryptography/ECDSA.sol";
+import {EIP712} from "@openzeppelin/contracts/utils/cryptography/EIP712.sol";
+import {SafeCast} from "@openzeppelin/contracts/utils/math/SafeCast.sol";
 
 /**
  * @title Bridge
@@ -19,18 +26,215 @@ import {IERC20} from "@openzeppelin/contracts/token/ERC20/IERC20.sol";
  * - Only offchain processor can mint tokens
  * - Fee calculations protected against overflow
  * - Uses OpenZeppelin's Ownable and Pausable for security
+ * - Fee, kill switch and chain administration live in the linked BridgeAdminLib
  */
-contract Bridge is Ownable, Pausable {
+contract Bridge is Ownable, Pausable, EIP712, IBridgeAdmin {
+    using BridgeAdminLib for BridgeAdminLib.FeeSchedule;
+    using BridgeAdminLib for BridgeAdminLib.KillSwitch;
+    using BridgeAdminLib for BridgeAdminLib.ChainRegistry;
+
     // Core state variables
     address public tokenAddress;
-    uint256 public transferFee;    // percentage (e.g., 100 = 1%)
-    uint256 public operationFee;   // flat fee in tokens
-    uint256 private constant FEE_DENOMINATOR = 10000;
+    uint8 public tokenDecimals;
+
+    // Transfer and operation fees, including a scheduled transfer fee change
+    BridgeAdminLib.FeeSchedule internal feeSchedule;
     address public offchainProcessor;
+    address public backupProcessor;  // failover processor that may also mint
+
+    // Keeps decimal scaling math well within uint256 bounds
+    uint8 private constant MAX_TOKEN_DECIMALS = 18;
+
+    // Upper bound on exchange deposit memos attached to mints
+    uint256 private constant MAX_MEMO_LENGTH = 256;
+
+    // Reason codes reported by validateBridge
+    uint8 public constant REJECT_NONE = 0;
+    uint8 public constant REJECT_PAUSED = 1;
+    uint8 public constant REJECT_ZERO_AMOUNT = 2;
+    uint8 public constant REJECT_INVALID_CHAIN = 3;
+    uint8 public constant REJECT_INVALID_DESTINATION = 4;
+    uint8 public constant REJECT_INSUFFICIENT_ALLOWANCE = 5;
+    uint8 public constant REJECT_FEE_EXCEEDS_AMOUNT = 6;
+    uint8 public constant REJECT_AMOUNT_AFTER_FEE_TOO_SMALL = 7;
+    uint8 public constant REJECT_USER_DAILY_LIMIT = 8;
+    uint8 public constant REJECT_CHAIN_PAUSED = 9;
+    uint8 public constant REJECT_NOT_DIVISIBLE = 10;
+    uint8 public constant REJECT_LOW_NATIVE_BALANCE = 11;
+    uint8 public constant REJECT_KILLED = 12;
+    uint8 public constant REJECT_BELOW_CHAIN_MINIMUM = 13;
+    uint8 public constant REJECT_CHAIN_NOT_ALLOWED = 14;
+
+    // Bits of eventMask; a set bit mutes that event. Only these high-volume events are maskable,
+    // events that relayers or audits depend on (BridgeStarted, pauses, admin changes) always fire.
+    uint16 public constant EVENT_FEE_UPDATED = 1 << 0;
+    uint16 public constant EVENT_ASSET_MINTED = 1 << 1;
+    uint16 public constant EVENT_BRIDGE_ROUTE = 1 << 2;
+
+    // Layout version of the config snapshot, bumped whenever its fields change
+    uint256 public constant CONFIG_SNAPSHOT_VERSION = 1;
+
+    // Bridge limits
+    uint256 public minBridgedAmount; // minimum amount delivered after fees
+    uint256 public minUserNativeBalance; // native balance a user must hold to bridge, for later gas (0 disables)
+    mapping(address => bool) public feeExempt;
+    uint256 public maxTotalSupply;   // hard ceiling on token supply after mints (0 disables)
+    uint256 public maxLifetimePerRecipient; // max total any recipient can ever be minted (0 disables)
+    mapping(address => uint256) public lifetimeMinted; // always tracked, unlike the window volumes
+    bool public mintingRelinquished; // set on sunset, permanently disables mintAsset
+    uint256 public pausedAt;         // timestamp of the current pause
+    uint256 public minPauseDuration; // cooling-off period in pause before sunset actions
+    uint256 public withdrawCooldown; // minimum seconds between fee withdrawals (0 disables)
+    uint256 public lastWithdrawAt;
+    bool public treasuryAllowlistEnabled; // restricts withdrawFees to approved treasuries
+    mapping(address => bool) public approvedTreasuries;
+    BridgeAdminLib.KillSwitch internal killSwitch; // emergency stop for all token movement
+
+    // Per-recipient windowed mint rate limiting
+    uint256 public maxPerRecipientWindow; // max minted to one recipient per window (0 disables)
+    uint256 public recipientWindowDuration;
+    mapping(address => uint256) public recipientWindowVolume;
+    mapping(address => uint256) public recipientWindowStart;
+
+    // Per-destination-chain settings, fee earmarks and the destination chain allowlist
+    BridgeAdminLib.ChainRegistry internal chainRegistry;
+
+    // Automatic fee collection once the shared fee pool reaches a threshold
+    uint256 public feeSweepThreshold; // 0 disables
+    address public feeSweepRecipient;
+
+    // Flat fee paid in the native currency instead of tokens, so the full amount less the transfer fee is bridged
+    uint256 public nativeOperationFee; // wei per bridge (0 charges operationFee in tokens)
+    address public nativeFeeCollector;
+    uint256 public accruedNativeFees; // held until withdrawNativeFees, so a failing collector cannot block bridging
+
+    // Net tokens moved off this chain by the bridge: burns add, mints subtract
+    int256 public circulatingOnRemote;
+
+    // XOR of the hashes of all approved treasuries and fee exemptions, so configHash covers these
+    // mappings without iterating them; chainRegistry keeps the same digest for chain configs
+    bytes32 private keyedConfigDigest;
+
+    // Relayer-supplied idempotency keys of completed mints
+    mapping(bytes32 => bool) public processedMints;
+
+    // Per-user outbound rate limiting
+    uint256 private constant DAILY_WINDOW = 1 days;
+    uint256 public maxDailyPerUser;  // max amount a single user can bridge per window (0 disables)
+    mapping(address => uint256) public userDailyVolume;
+    mapping(address => uint256) public userWindowStart;
+
+    // Bridge-wide totals for the current window, summarized in DailyVolume at rollover.
+    // Only tracked while verboseEvents is on, keeping the writes off the default bridge path.
+    uint256 public dailyWindowStart;
+    uint256 public dailyWindowVolume;
+    uint256 public dailyWindowBridgeCount;
+
+    // Outbound message sequencing
+    uint256 public outboundNonce;    // number of bridges started, used as message sequence
+    bool public genericMessagesEnabled;
+    bool public burnReceiptsEnabled;
+    uint256 public minConfirmations; // recommended source-chain confirmations before relaying a burn
+    bool public verboseEvents;       // extra accounting events for reconciliation
+    string public localChainName;    // this chain's identifier in BridgeRoute events
+    uint16 public eventMask;         // EVENT_* bits muted to reduce log volume
+
+    // Signed bridge authorizations submitted by relayers
+    bytes32 private constant BRIDGE_PERMIT_TYPEHASH = keccak256(
+        "BridgePermit(address user,uint256 amount,string destinationChain,address destinationAddress,uint256 nonce,uint256 deadline)"
+    );
+    mapping(address => uint256) public permitNonces;
+
+    // Bridging budgets users grant to custodial front-ends, user => delegate => amount
+    mapping(address => mapping(address => uint256)) public bridgeDelegations;
+
+    // Optional contract notified after each mint
+    address public postMintHook;
+    bool public revertOnHookFailure;
+
+    // Commonly read configuration, returned in one call for front-ends
+    struct BridgeSummary {
+        address tokenAddress;
+        uint8 tokenDecimals;
+        uint256 transferFee;
+        uint256 feePrecision;
+        uint256 operationFee;
+        uint256 nativeOperationFee;
+        address nativeFeeCollector;
+        uint256 maxTotalFee;
+        uint256 minBridgedAmount;
+        uint256 maxTotalSupply;
+        address offchainProcessor;
+        bool paused;
+    }
+
+    // Full configuration returned by getBridgeConfig and hashed by configHash
+    struct BridgeConfig {
+        uint256 version;
+        address tokenAddress;
+        uint8 tokenDecimals;
+        address owner;
+        FeeConfig fees;
+        MintingConfig minting;
+        OperationsConfig operations;
+        bytes32 keyedConfigDigest;
+    }
+
+    struct FeeConfig {
+        uint256 transferFee;
+        uint256 feePrecision;
+        uint256 operationFee;
+        uint256 maxTotalFee;
+        uint256 minFeeBasisPoints;
+        uint256 feeChangeDelay;
+        uint256 pendingTransferFee;
+        uint256 pendingFeeEffectiveAt;
+        uint256 nativeOperationFee;
+        address nativeFeeCollector;
+        uint256 feeSweepThreshold;
+        address feeSweepRecipient;
+        bool treasuryAllowlistEnabled;
+        uint256 withdrawCooldown;
+    }
+
+    struct MintingConfig {
+        address offchainProcessor;
+        address backupProcessor;
+        address postMintHook;
+        bool revertOnHookFailure;
+        uint256 maxTotalSupply;
+        uint256 maxLifetimePerRecipient;
+        uint256 maxPerRecipientWindow;
+        uint256 recipientWindowDuration;
+    }
+
+    struct OperationsConfig {
+        bool paused;
+        bool mintingRelinquished;
+        uint256 minPauseDuration;
+        bool killSwitchTripped;
+        uint256 killSwitchResetDelay;
+        uint256 killSwitchResetReadyAt;
+        uint256 minBridgedAmount;
+        uint256 minUserNativeBalance;
+        uint256 maxDailyPerUser;
+        uint256 maxChainConfigs;
+        bool chainAllowlistEnabled;
+        uint16 eventMask;
+        bool verboseEvents;
+        bool burnReceiptsEnabled;
+        bool genericMessagesEnabled;
+        uint256 minConfirmations;
+        string localChainName;
+    }
 
-    // Maximum fee constraints
-    uint256 private constant MAX_TRANSFER_FEE = 1000; // 10%
-    uint256 private constant MAX_OPERATION_FEE = 1000 * 10 ** 18; // 1000 tokens
+    // Confirmation returned by receiveAsset so calling contracts can branch on it
+    struct ReceiveResult {
+        uint256 bridgeId;
+        uint256 fee;
+        uint256 amountAfterFee;
+        uint256 blockNumber;
+    }
 
     // Events for tracking bridge operations
     event BridgeStarted(
@@ -41,14 +245,68 @@ contract Bridge is Ownable, Pausable {
         address destinationAddress
     );
 
+    // Standardized (emitter, sequence, payload) message for generic relayers
+    event MessagePublished(
+        address indexed emitter,
+        uint256 indexed sequence,
+        bytes payload
+    );
+
+    // Amount after fee restated in the destination chain's token decimals
+    event BridgeAmountScaled(
+        address indexed user,
+        string destinationChain,
+        uint256 scaledAmount,
+        uint8 remoteDecimals
+    );
+
+    // Compact proof-of-burn for relayers to attest to on the destination chain
+    event BurnReceipt(
+        uint256 indexed bridgeId,
+        uint256 amountBurned,
+        uint256 burnBlock,
+        address token,
+        uint256 minConfirmations
+    );
+
+    // Change in token supply from a burn (negative) or mint (positive), with the supply after it
+    event SupplyDelta(
+        address indexed token,
+        int256 delta,
+        uint256 newSupply
+    );
+
+    // User's token balance once a bridge completes, for reconciliation
+    event UserBalanceAfter(
+        address indexed user,
+        uint256 balance
+    );
+
+    // Remainder dropped by integer division of the transfer fee, in 1/feePrecision token units
+    event FeeRounding(
+        address indexed user,
+        uint256 remainder
+    );
+
     event AssetMinted(
         address indexed recipient,
         uint256 amount
     );
 
-    event FeeUpdated(
-        uint256 newTransferFee,
-        uint256 newOperationFee
+    event MintMemo(
+        address indexed recipient,
+        uint256 amount,
+        string memo
+    );
+
+    event MintKeyProcessed(
+        bytes32 indexed idempotencyKey
+    );
+
+    event PostMintHookFailed(
+        address indexed hook,
+        address indexed recipient,
+        uint256 amount
     );
 
     event OffchainProcessorChanged(
@@ -56,17 +314,234 @@ contract Bridge is Ownable, Pausable {
         address indexed newProcessor
     );
 
+    event BackupProcessorChanged(
+        address indexed oldProcessor,
+        address indexed newProcessor
+    );
+
+    event MintingRelinquished();
+
+    event MinPauseDurationUpdated(
+        uint256 newMinPauseDuration
+    );
+
+    event WithdrawCooldownUpdated(
+        uint256 newWithdrawCooldown
+    );
+
+    event TreasuryApprovalUpdated(
+        address indexed treasury,
+        bool approved
+    );
+
+    event TreasuryAllowlistToggled(
+        bool enabled
+    );
+
+    event ChainFeesWithdrawn(
+        string chain,
+        address indexed to,
+        uint256 amount
+    );
+
     event FeesWithdrawn(
         address indexed to,
         uint256 amount
     );
 
+    event FeeExemptBridge(
+        address indexed user,
+        uint256 amount
+    );
+
+    event FeeExemptionUpdated(
+        address indexed user,
+        bool exempt
+    );
+
+    event PostMintHookUpdated(
+        address indexed hook,
+        bool revertOnFailure
+    );
+
+    event VerboseEventsToggled(
+        bool enabled
+    );
+
+    // Uniform route record for both legs; mints carry no source, so it is left empty
+    event BridgeRoute(
+        string sourceChain,
+        string destinationChain,
+        uint256 amount,
+        uint256 fee
+    );
+
+    event LocalChainNameUpdated(
+        string newLocalChainName
+    );
+
+    event EventMaskUpdated(
+        uint16 newEventMask
+    );
+
+    event BurnReceiptsToggled(
+        bool enabled
+    );
+
+    event MinConfirmationsUpdated(
+        uint256 newMinConfirmations
+    );
+
+    event GenericMessagesToggled(
+        bool enabled
+    );
+
+    event MaxDailyPerUserUpdated(
+        uint256 newMaxDailyPerUser
+    );
+
+    // Totals of a finished window, emitted by the first bridge after it ends
+    event DailyVolume(
+        uint256 windowStart,
+        uint256 totalVolume,
+        uint256 bridgeCount
+    );
+
+    event MaxLifetimePerRecipientUpdated(
+        uint256 newMaxLifetimePerRecipient
+    );
+
+    event RecipientWindowCapUpdated(
+        uint256 maxPerWindow,
+        uint256 windowDuration
+    );
+
+    event MaxTotalSupplyUpdated(
+        uint256 newMaxTotalSupply
+    );
+
+    event MinBridgedAmountUpdated(
+        uint256 newMinBridgedAmount
+    );
+
+    event MinUserNativeBalanceUpdated(
+        uint256 newMinUserNativeBalance
+    );
+
+    event BridgeRejected(
+        address indexed user,
+        uint8 reasonCode
+    );
+
+    event BridgeDelegateApproved(
+        address indexed user,
+        address indexed delegate,
+        uint256 amount
+    );
+
+    event DelegatedBridge(
+        address indexed user,
+        address indexed delegate,
+        uint256 amount
+    );
+
+    event FeeSweepUpdated(
+        uint256 threshold,
+        address indexed recipient
+    );
+
+    event NativeFeesWithdrawn(
+        address indexed collector,
+        uint256 amount
+    );
+
+    event NativeOperationFeeUpdated(
+        uint256 fee,
+        address indexed collector
+    );
+
+    // Headline fields only; configHash covers everything getBridgeConfig returns
+    event ConfigSnapshot(
+        uint256 version,
+        address tokenAddress,
+        uint256 transferFee,
+        uint256 feePrecision,
+        uint256 operationFee,
+        address offchainProcessor,
+        address backupProcessor,
+        address owner,
+        bool paused,
+        bytes32 configHash
+    );
+
+    // Balances recorded alongside the config snapshot when pausing for an incident
+    event EmergencySnapshot(
+        uint256 pausedAt,
+        uint256 bridgeBalance,
+        uint256 earmarkedFees,
+        uint256 totalSupply,
+        int256 circulatingOnRemote
+    );
+
+    // Fee, kill switch and chain configuration errors are declared in IBridgeAdmin
+    error ZeroAmount();
+    error InvalidTokenAddress();
+    error InvalidOracleAddress();
+    error InvalidProcessorAddress();
+    error ProcessorNotInitialized();
+    error NotOffchainProcessor();
+    error BackupMatchesPrimary();
+    error NoBackupProcessor();
+    error InvalidDestinationChain();
+    error InvalidDestinationAddress();
+    error ChainNotAllowed();
+    error InsufficientNativeBalance();
+    error IncorrectNativeFee();
+    error InsufficientAllowance();
+    error FeeExceedsAmount();
+    error AmountAfterFeeTooSmall();
+    error UserDailyLimitExceeded();
+    error NotDivisible();
+    error AmountBelowChainMinimum();
+    error TransferFailed();
+    error PermitExpired();
+    error InvalidPermitSignature();
+    error InvalidDelegate();
+    error DelegationExceeded();
+    error InvalidRecipient();
+    error MintToBridge();
+    error MintingDisabled();
+    error MintAlreadyProcessed();
+    error MemoTooLong();
+    error SupplyCapExceeded();
+    error RecipientCapExceeded();
+    error RecipientWindowCapExceeded();
+    error HookNotContract();
+    error InvalidWindow();
+    error InvalidUserAddress();
+    error PauseTooRecent();
+    error WithdrawTooFrequent();
+    error UnapprovedDestination();
+    error NoFeesToWithdraw();
+    error NoChainFeeRecipient();
+    error FeeWithdrawalFailed();
+    error FeeSweepFailed();
+    error NativeFeeTransferFailed();
+
     /**
-     * @dev Modifier to restrict functions to offchain processor
+     * @dev Modifier to restrict functions to the offchain processor or its backup
      */
     modifier onlyOffchain() {
-        require(msg.sender == offchainProcessor, "Only Offchain Processor allowed to call this method");
-        require(offchainProcessor != address(0), "Offchain processor not initialized");
+        if (msg.sender != offchainProcessor && msg.sender != backupProcessor) revert NotOffchainProcessor();
+        if (offchainProcessor == address(0)) revert ProcessorNotInitialized();
+        _;
+    }
+
+    /**
+     * @dev Modifier to block token movement once the kill switch is tripped
+     */
+    modifier whenNotKilled() {
+        if (killSwitch.tripped) revert KillSwitchActive();
         _;
     }
 
@@ -84,16 +559,18 @@ contract Bridge is Ownable, Pausable {
         uint256 _operationFee,
         address oracle,
         address _offchainProcessor
-    )  {
-        require(_token != address(0), "Invalid token address");
-        require(oracle != address(0), "Invalid oracle address");
-        require(_offchainProcessor != address(0), "Invalid processor address");
-        require(_transferFee <= MAX_TRANSFER_FEE, "Transfer fee too high");
-        require(_operationFee <= MAX_OPERATION_FEE, "Operation fee too high");
+    ) EIP712("Bridge", "1") {
+        if (_token == address(0)) revert InvalidTokenAddress();
+        if (oracle == address(0)) revert InvalidOracleAddress();
+        if (_offchainProcessor == address(0)) revert InvalidProcessorAddress();
+
+        uint8 decimals = IERC20Metadata(_token).decimals();
+        if (decimals > MAX_TOKEN_DECIMALS) revert UnsupportedTokenDecimals();
 
         tokenAddress = _token;
-        transferFee = _transferFee;
-        operationFee = _operationFee;
+        tokenDecimals = decimals;
+        feeSchedule.initialize(_transferFee, _operationFee);
+        killSwitch.initialize();
         offchainProcessor = _offchainProcessor;
 
         _transferOwnership(oracle);
@@ -104,48 +581,240 @@ contract Bridge is Ownable, Pausable {
      * @param amount Amount of tokens to bridge
      * @param destinationChain Target chain identifier
      * @param destinationAddress Recipient address on target chain
+     * @return result Confirmation of the bridge for contracts composing this call
      *
      * Security:
      * - Validates allowance and balances
      * - Calculates fees with overflow protection
      * - Burns tokens after successful transfer
-     * - Admin (owner) is exempt from fees
+     * - Admin (owner) and fee-exempt users pay no fees
+     * - Enforces the per-user daily outbound cap
+     * - Rejects destination chains missing from the chain allowlist while it is enabled
+     *
+     * Ordering: validate -> account writes -> transfer in -> burn -> emit -> pay out.
+     * Account writes precede the token calls (checks-effects-interactions), and
+     * any failing transfer or burn reverts them, so no bridge is ever recorded
+     * without its tokens having moved. The fee sweep is the only outbound
+     * transfer and runs last; native fees are held for withdrawNativeFees.
      */
     function receiveAsset(
         uint256 amount,
         string memory destinationChain,
         address destinationAddress
-    ) external whenNotPaused {
-        require(amount != 0, "Amount must be greater than 0");
-        require(bytes(destinationChain).length != 0, "Invalid destination chain");
-        require(destinationAddress != address(0), "Invalid destination address");
+    ) external payable whenNotPaused whenNotKilled returns (ReceiveResult memory result) {
+        (uint256 bridgeId, uint256 fee, uint256 amountAfterFee) =
+            _receiveAsset(msg.sender, amount, destinationChain, destinationAddress, true);
+        return _receiveResult(bridgeId, fee, amountAfterFee);
+    }
+
+    /**
+     * @dev Initiates a bridge transfer authorized by the user's signed permit
+     * @param user Address whose tokens are bridged and who signed the permit
+     * @param amount Amount of tokens to bridge
+     * @param destinationChain Target chain identifier
+     * @param destinationAddress Recipient address on target chain
+     * @param deadline Timestamp after which the permit is no longer valid
+     * @param signature EIP-712 signature by the user over the BridgePermit
+     * @return result Confirmation of the bridge
+     *
+     * Lets a relayer submit and pay gas for the bridge, but is not fully gasless:
+     * the token does not implement ERC-2612, so the user must already have
+     * approved the bridge for at least amount in an ordinary transaction.
+     *
+     * Security:
+     * - Signature binds amount, destination, nonce and deadline
+     * - Nonces are consumed so a permit can only be used once
+     * - Tokens are pulled using the user's existing allowance to the bridge
+     * - minUserNativeBalance is not enforced, so users without gas can still bridge
+     */
+    function receiveAssetWithPermit(
+        address user,
+        uint256 amount,
+        string memory destinationChain,
+        address destinationAddress,
+        uint256 deadline,
+        bytes memory signature
+    ) external payable whenNotPaused whenNotKilled returns (ReceiveResult memory result) {
+        if (block.timestamp > deadline) revert PermitExpired();
+
+        bytes32 structHash = keccak256(abi.encode(
+            BRIDGE_PERMIT_TYPEHASH,
+            user,
+            amount,
+            keccak256(bytes(destinationChain)),
+            destinationAddress,
+            permitNonces[user]++,
+            deadline
+        ));
+        if (ECDSA.recover(_hashTypedDataV4(structHash), signature) != user) revert InvalidPermitSignature();
+
+        (uint256 bridgeId, uint256 fee, uint256 amountAfterFee) =
+            _receiveAsset(user, amount, destinationChain, destinationAddress, false);
+        return _receiveResult(bridgeId, fee, amountAfterFee);
+    }
+
+    /**
+     * @dev Lets a delegate bridge up to amount of the caller's tokens
+     * @param delegate Address allowed to call receiveAssetDelegated for the caller
+     * @param amount Total amount the delegate may bridge, replacing any previous budget
+     */
+    function approveBridgeDelegate(address delegate, uint256 amount) external {
+        if (delegate == address(0)) revert InvalidDelegate();
+        bridgeDelegations[msg.sender][delegate] = amount;
+        emit BridgeDelegateApproved(msg.sender, delegate, amount);
+    }
+
+    /**
+     * @dev Bridges a user's tokens on their behalf using a delegated budget
+     * @param user Token owner being bridged for
+     * @param amount Amount of tokens to bridge
+     * @param destinationChain Target chain identifier
+     * @param destinationAddress Recipient address on target chain
+     * @return result Confirmation of the bridge
+     *
+     * Security:
+     * - Caller must hold a delegation from user covering amount
+     * - Tokens are pulled using the user's own allowance to the bridge
+     * - minUserNativeBalance is not enforced, as the delegate submits and pays the gas
+     */
+    function receiveAssetDelegated(
+        address user,
+        uint256 amount,
+        string memory destinationChain,
+        address destinationAddress
+    ) external payable whenNotPaused whenNotKilled returns (ReceiveResult memory result) {
+        uint256 delegated = bridgeDelegations[user][msg.sender];
+        if (delegated < amount) revert DelegationExceeded();
+        bridgeDelegations[user][msg.sender] = delegated - amount;
+
+        (uint256 bridgeId, uint256 fee, uint256 amountAfterFee) =
+            _receiveAsset(user, amount, destinationChain, destinationAddress, false);
+        emit DelegatedBridge(user, msg.sender, amount);
+        return _receiveResult(bridgeId, fee, amountAfterFee);
+    }
+
+    /**
+     * @dev Performs the bridge transfer on behalf of a user
+     * @param checkUserNativeBalance Whether user must hold minUserNativeBalance, false when someone else pays the gas
+     */
+    function _receiveAsset(
+        address user,
+        uint256 amount,
+        string memory destinationChain,
+        address destinationAddress,
+        bool checkUserNativeBalance
+    ) internal returns (uint256 sequence, uint256 totalFee, uint256 amountAfterFee) {
+        if (amount == 0) revert ZeroAmount();
+        if (bytes(destinationChain).length == 0) revert InvalidDestinationChain();
+        if (destinationAddress == address(0)) revert InvalidDestinationAddress();
+        bytes32 chainKey = _chainKey(destinationChain);
+        BridgeAdminLib.ChainConfig storage chainConfig = chainRegistry.configs[chainKey];
+        if (chainConfig.paused) revert ChainPaused();
+        if (chainRegistry.allowlistEnabled && !chainRegistry.allowed[chainKey]) revert ChainNotAllowed();
+        if (checkUserNativeBalance && minUserNativeBalance != 0) {
+            if (user.balance < minUserNativeBalance) revert InsufficientNativeBalance();
+        }
+        if (msg.value != _nativeFeeDue(user)) revert IncorrectNativeFee();
+        feeSchedule.applyPendingTransferFee(_eventEnabled(EVENT_FEE_UPDATED));
 
         TokenManager token = TokenManager(tokenAddress);
-        address thisAddress = address(this);
 
-        uint256 allowance = token.allowance(msg.sender, thisAddress);
-        require(allowance >= amount, "Insufficient allowance");
+        if (token.allowance(user, address(this)) < amount) revert InsufficientAllowance();
 
-        // Calculate fees - exempt for admin
-        uint256 totalFee = 0;
-        uint256 amountAfterFee = amount;
+        // Calculate fees - exempt for admin and fee-exempt users
+        totalFee = _calculateFee(user, amount);
+        if (totalFee >= amount) revert FeeExceedsAmount();
+        amountAfterFee = amount - totalFee;
+        if (amountAfterFee < minBridgedAmount) revert AmountAfterFeeTooSmall();
 
-        if (msg.sender != owner()) {
-            // Calculate fees with overflow protection
-            uint256 transferFeeAmount = (amount * transferFee) / FEE_DENOMINATOR;
-            totalFee = transferFeeAmount + operationFee;
-            require(totalFee < amount, "Fee exceeds amount");
-            amountAfterFee = amount - totalFee;
+        if (maxDailyPerUser != 0) {
+            uint256 userVolume = _userVolumeAfter(user, amount);
+            if (userVolume > maxDailyPerUser) revert UserDailyLimitExceeded();
+            if (block.timestamp >= userWindowStart[user] + DAILY_WINDOW) {
+                userWindowStart[user] = block.timestamp;
+            }
+            userDailyVolume[user] = userVolume;
+        }
+        if (verboseEvents) {
+            _recordDailyVolume(amount);
+        }
+        if (totalFee != 0 && chainConfig.feeRecipient != address(0)) {
+            chainRegistry.collectedFees[chainKey] += totalFee;
+            chainRegistry.earmarkedFees += totalFee;
         }
+        accruedNativeFees += msg.value;
+        sequence = ++outboundNonce;
 
-        require(token.transferFrom(msg.sender, thisAddress, amount), "Transfer failed");
+        // Fee-on-transfer tokens may deliver less than amount, so burn based on what arrived
+        {
+            uint256 received = _pullTokens(token, user, amount);
+            if (received <= totalFee) revert FeeExceedsAmount();
+            amountAfterFee = received - totalFee;
+            if (amountAfterFee < minBridgedAmount) revert AmountAfterFeeTooSmall();
+            if (!_isRemoteDivisible(chainConfig, amountAfterFee)) revert NotDivisible();
+            if (amountAfterFee < chainConfig.minAmount) revert AmountBelowChainMinimum();
+        }
 
         // Burn only the amount after fees, keep fees in contract
         if (amountAfterFee > 0) {
-            token.burnFrom(thisAddress, amountAfterFee);
+            token.burnFrom(address(this), amountAfterFee);
+            if (verboseEvents) {
+                _emitSupplyDelta(-SafeCast.toInt256(amountAfterFee));
+            }
+        }
+        circulatingOnRemote += SafeCast.toInt256(amountAfterFee);
+
+        if (feeExempt[user]) {
+            emit FeeExemptBridge(user, amount);
+        }
+        emit BridgeStarted(user, amount, amountAfterFee, destinationChain, destinationAddress);
+        if (_eventEnabled(EVENT_BRIDGE_ROUTE)) {
+            emit BridgeRoute(localChainName, destinationChain, amount, totalFee);
+        }
+        _emitScaledAmount(user, destinationChain, chainConfig, amountAfterFee);
+
+        if (verboseEvents) {
+            // A waived or capped fee is not the result of the division, so nothing was rounded off
+            if (!_isFeeExempt(user) && !_isFeeCapped(amount)) {
+                emit FeeRounding(user, (amount * currentTransferFee()) % feeSchedule.precision);
+            }
+            emit UserBalanceAfter(user, token.balanceOf(user));
+        }
+
+        if (burnReceiptsEnabled) {
+            emit BurnReceipt(sequence, amountAfterFee, block.number, tokenAddress, minConfirmations);
         }
 
-        emit BridgeStarted(msg.sender, amount, amountAfterFee, destinationChain, destinationAddress);
+        if (genericMessagesEnabled) {
+            emit MessagePublished(
+                address(this),
+                sequence,
+                abi.encode(user, amountAfterFee, destinationChain, destinationAddress)
+            );
+        }
+
+        _sweepFeesIfNeeded();
+    }
+
+    /**
+     * @dev Checks whether receiveAsset would accept a bridge without moving tokens
+     * @param amount Amount of tokens to bridge
+     * @param destinationChain Target chain identifier
+     * @param destinationAddress Recipient address on target chain
+     * @return reasonCode REJECT_NONE if accepted, otherwise the rejection reason
+     *
+     * A reverted receiveAsset discards all of its logs, so rejections can only be
+     * observed through this non-reverting path, which emits BridgeRejected instead.
+     */
+    function validateBridge(
+        uint256 amount,
+        string memory destinationChain,
+        address destinationAddress
+    ) external returns (uint8 reasonCode) {
+        reasonCode = _rejectionReason(msg.sender, amount, destinationChain, destinationAddress);
+        if (reasonCode != REJECT_NONE) {
+            emit BridgeRejected(msg.sender, reasonCode);
+        }
     }
 
     /**
@@ -154,60 +823,527 @@ contract Bridge is Ownable, Pausable {
      * @param amount Amount of tokens to mint
      *
      * Security:
-     * - Only callable by offchain processor
+     * - Only callable by offchain processor or backup processor
      * - Protected by pausable mechanism
+     * - Never mints the token supply past maxTotalSupply
+     * - Never mints a recipient past maxLifetimePerRecipient
+     * - Never mints a recipient past maxPerRecipientWindow within one window
+     * - Never mints into the bridge itself, which would be mistaken for fees
+     * - Calls the post-mint hook last, after state changes and events
      */
     function mintAsset(
         address to,
         uint256 amount
-    ) external onlyOffchain whenNotPaused {
-        require(to != address(0), "Invalid recipient");
-        require(amount != 0, "Amount must be greater than 0");
+    ) external onlyOffchain whenNotPaused whenNotKilled {
+        _mintAsset(to, amount);
+    }
+
+    /**
+     * @dev Mints tokens for a cross-chain transfer at most once per idempotency key
+     * @param to Recipient address
+     * @param amount Amount of tokens to mint
+     * @param idempotencyKey Relayer-chosen key identifying this transfer
+     *
+     * Security:
+     * - Same restrictions as mintAsset
+     * - A retry with an already used key is rejected
+     */
+    function mintAssetWithKey(
+        address to,
+        uint256 amount,
+        bytes32 idempotencyKey
+    ) external onlyOffchain whenNotPaused whenNotKilled {
+        if (processedMints[idempotencyKey]) revert MintAlreadyProcessed();
+        processedMints[idempotencyKey] = true;
+        emit MintKeyProcessed(idempotencyKey);
+        _mintAsset(to, amount);
+    }
+
+    /**
+     * @dev Mints tokens for a cross-chain transfer with an attached memo
+     * @param to Recipient address
+     * @param amount Amount of tokens to mint
+     * @param memo Deposit memo or tag required by exchanges (empty for none)
+     *
+     * Security: Same restrictions as mintAsset
+     */
+    function mintAssetWithMemo(
+        address to,
+        uint256 amount,
+        string memory memo
+    ) external onlyOffchain whenNotPaused whenNotKilled {
+        if (bytes(memo).length > MAX_MEMO_LENGTH) revert MemoTooLong();
+        if (bytes(memo).length != 0) {
+            emit MintMemo(to, amount, memo);
+        }
+        _mintAsset(to, amount);
+    }
+
+    /**
+     * @dev Performs a mint after the caller has been authorized
+     */
+    function _mintAsset(address to, uint256 amount) internal {
+        if (to == address(0)) revert InvalidRecipient();
+        if (to == address(this)) revert MintToBridge();
+        if (amount == 0) revert ZeroAmount();
+        if (mintingRelinquished) revert MintingDisabled();
 
         TokenManager token = TokenManager(tokenAddress);
+        if (maxTotalSupply != 0) {
+            if (token.totalSupply() + amount > maxTotalSupply) revert SupplyCapExceeded();
+        }
+
+        // Tracked even while the cap is disabled, so a cap enabled later counts earlier mints
+        uint256 minted = lifetimeMinted[to] + amount;
+        if (maxLifetimePerRecipient != 0) {
+            if (minted > maxLifetimePerRecipient) revert RecipientCapExceeded();
+        }
+        lifetimeMinted[to] = minted;
+
+        if (maxPerRecipientWindow != 0) {
+            uint256 windowVolume = recipientWindowVolume[to] + amount;
+            if (block.timestamp >= recipientWindowStart[to] + recipientWindowDuration) {
+                recipientWindowStart[to] = block.timestamp;
+                windowVolume = amount;
+            }
+            if (windowVolume > maxPerRecipientWindow) revert RecipientWindowCapExceeded();
+            recipientWindowVolume[to] = windowVolume;
+        }
+
+        circulatingOnRemote -= SafeCast.toInt256(amount);
         token.mint(to, amount);
+        if (verboseEvents) {
+            _emitSupplyDelta(SafeCast.toInt256(amount));
+        }
+
+        if (_eventEnabled(EVENT_ASSET_MINTED)) {
+            emit AssetMinted(to, amount);
+        }
+        if (_eventEnabled(EVENT_BRIDGE_ROUTE)) {
+            emit BridgeRoute("", localChainName, amount, 0);
+        }
 
-        emit AssetMinted(to, amount);
+        address hook = postMintHook;
+        if (hook != address(0)) {
+            if (revertOnHookFailure) {
+                IBridgeMintHook(hook).onBridgeMint(to, amount);
+            } else if (hook.code.length == 0) {
+                // A hook that lost its code would fail the extcodesize check before try/catch applies
+                emit PostMintHookFailed(hook, to, amount);
+            } else {
+                try IBridgeMintHook(hook).onBridgeMint(to, amount) {} catch {
+                    emit PostMintHookFailed(hook, to, amount);
+                }
+            }
+        }
     }
 
     /**
      * @dev Updates the transfer fee percentage
-     * @param newFee New fee in basis points
+     * @param newFee New fee in units of feePrecision
+     *
+     * When feeChangeDelay is set the new fee is scheduled and only applies
+     * once the delay has elapsed, so quotes issued before the change hold.
+     * A change still scheduled is cancelled and replaced.
      *
      * Security: Only callable by owner (Oracle)
      */
     function updateTransferFee(uint256 newFee) external onlyOwner {
-        require(newFee <= MAX_TRANSFER_FEE, "Fee too high");
-        transferFee = newFee;
-        emit FeeUpdated(newFee, operationFee);
+        feeSchedule.updateTransferFee(newFee, _eventEnabled(EVENT_FEE_UPDATED));
     }
 
     /**
-     * @dev Updates the flat operation fee
-     * @param newFee New fee amount
+     * @dev Updates the delay before transfer fee changes take effect
+     * @param newDelay New delay in seconds (0 applies changes immediately)
      *
      * Security: Only callable by owner (Oracle)
      */
-    function updateOperationFee(uint256 newFee) external onlyOwner {
-        require(newFee <= MAX_OPERATION_FEE, "Fee too high");
-        operationFee = newFee;
-        emit FeeUpdated(transferFee, newFee);
+    function updateFeeChangeDelay(uint256 newDelay) external onlyOwner {
+        feeSchedule.updateFeeChangeDelay(newDelay);
     }
 
     /**
-     * @dev Pauses bridge operations
-     * Security: Only callable by owner (Oracle)
+     * @dev Returns the transfer fee charged right now, including a matured scheduled change
+     * @return Transfer fee in units of feePrecision
      */
-    function pause() external onlyOwner {
-        _pause();
+    function currentTransferFee() public view returns (uint256) {
+        return feeSchedule.currentTransferFee();
     }
 
     /**
-     * @dev Unpauses bridge operations
-     * Security: Only callable by owner (Oracle)
+     * @dev Switches the transfer fee divisor, restating the fee at the new precision
+     * @param newPrecision New divisor, a multiple of 10000 up to 1000000
+     * @param newFee Transfer fee expressed in units of the new precision
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - With feeChangeDelay set, newFee must restate the live rate exactly,
+     *   so the delay on rate changes cannot be bypassed
+     * - Rejected while a fee change is scheduled, as it is expressed at the old precision
      */
-    function unpause() external onlyOwner {
-        _unpause();
+    function updateFeePrecision(uint256 newPrecision, uint256 newFee) external onlyOwner {
+        feeSchedule.updateFeePrecision(newPrecision, newFee, _eventEnabled(EVENT_FEE_UPDATED));
+    }
+
+    /**
+     * @dev Updates the flat operation fee
+     * @param newFee New fee amount
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function updateOperationFee(uint256 newFee) external onlyOwner {
+        feeSchedule.updateOperationFee(newFee, _eventEnabled(EVENT_FEE_UPDATED));
+    }
+
+    /**
+     * @dev Updates the absolute cap on the combined fee charged per bridge
+     * @param newMaxTotalFee New cap in tokens (0 disables)
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Must not be below the flat operation fee
+     */
+    function updateMaxTotalFee(uint256 newMaxTotalFee) external onlyOwner {
+        feeSchedule.updateMaxTotalFee(newMaxTotalFee);
+    }
+
+    /**
+     * @dev Updates the lowest transfer fee that may be set
+     * @param newMinFeeBasisPoints New floor in basis points
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Must not exceed MAX_TRANSFER_FEE, the current transfer fee or a scheduled one
+     */
+    function updateMinFeeBasisPoints(uint256 newMinFeeBasisPoints) external onlyOwner {
+        feeSchedule.updateMinFeeBasisPoints(newMinFeeBasisPoints, _eventEnabled(EVENT_FEE_UPDATED));
+    }
+
+    /**
+     * @dev Updates the minimum amount that must remain after fees
+     * @param newMinBridgedAmount New minimum amount after fees (0 disables)
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function updateMinBridgedAmount(uint256 newMinBridgedAmount) external onlyOwner {
+        minBridgedAmount = newMinBridgedAmount;
+        emit MinBridgedAmountUpdated(newMinBridgedAmount);
+    }
+
+    /**
+     * @dev Updates the native balance a user must hold to bridge
+     * @param newMinUserNativeBalance New minimum in wei (0 disables)
+     *
+     * Not applied to receiveAssetWithPermit or receiveAssetDelegated, where someone else pays the gas.
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function updateMinUserNativeBalance(uint256 newMinUserNativeBalance) external onlyOwner {
+        minUserNativeBalance = newMinUserNativeBalance;
+        emit MinUserNativeBalanceUpdated(newMinUserNativeBalance);
+    }
+
+    /**
+     * @dev Sets the contract notified after each successful mint
+     * @param hook Hook contract address (zero disables)
+     * @param revertOnFailure Whether a failing hook reverts the mint or is only logged
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Rejects addresses without code, whose calls would revert outside try/catch
+     */
+    function setPostMintHook(address hook, bool revertOnFailure) external onlyOwner {
+        if (hook != address(0) && hook.code.length == 0) revert HookNotContract();
+        postMintHook = hook;
+        revertOnHookFailure = revertOnFailure;
+        emit PostMintHookUpdated(hook, revertOnFailure);
+    }
+
+    /**
+     * @dev Enables or disables verbose accounting events
+     * @param enabled Whether receiveAsset emits extra reconciliation events
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setVerboseEvents(bool enabled) external onlyOwner {
+        if (enabled && !verboseEvents) {
+            // Totals left from an earlier verbose period miss the bridges made since, so start over
+            dailyWindowStart = 0;
+            dailyWindowVolume = 0;
+            dailyWindowBridgeCount = 0;
+        }
+        verboseEvents = enabled;
+        emit VerboseEventsToggled(enabled);
+    }
+
+    /**
+     * @dev Updates the name this chain uses in BridgeRoute events
+     * @param newLocalChainName Chain identifier, matching what remote bridges use as destinationChain
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setLocalChainName(string memory newLocalChainName) external onlyOwner {
+        localChainName = newLocalChainName;
+        emit LocalChainNameUpdated(newLocalChainName);
+    }
+
+    /**
+     * @dev Updates which optional events are muted
+     * @param newEventMask Bitmask of EVENT_* constants to suppress (0 emits everything)
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setEventMask(uint16 newEventMask) external onlyOwner {
+        eventMask = newEventMask;
+        emit EventMaskUpdated(newEventMask);
+    }
+
+    /**
+     * @dev Enables or disables the BurnReceipt event
+     * @param enabled Whether receiveAsset also emits a proof-of-burn receipt
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setBurnReceiptsEnabled(bool enabled) external onlyOwner {
+        burnReceiptsEnabled = enabled;
+        emit BurnReceiptsToggled(enabled);
+    }
+
+    /**
+     * @dev Updates the confirmation depth relayers should wait for before relaying a burn
+     * @param newMinConfirmations Number of blocks, carried in each BurnReceipt
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function updateMinConfirmations(uint256 newMinConfirmations) external onlyOwner {
+        minConfirmations = newMinConfirmations;
+        emit MinConfirmationsUpdated(newMinConfirmations);
+    }
+
+    /**
+     * @dev Enables or disables the standardized MessagePublished event
+     * @param enabled Whether receiveAsset also publishes a generic message
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setGenericMessagesEnabled(bool enabled) external onlyOwner {
+        genericMessagesEnabled = enabled;
+        emit GenericMessagesToggled(enabled);
+    }
+
+    /**
+     * @dev Updates the maximum amount a single user can bridge per day
+     * @param newMaxDailyPerUser New per-user daily cap (0 disables)
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function updateMaxDailyPerUser(uint256 newMaxDailyPerUser) external onlyOwner {
+        maxDailyPerUser = newMaxDailyPerUser;
+        emit MaxDailyPerUserUpdated(newMaxDailyPerUser);
+    }
+
+    /**
+     * @dev Updates the hard cap on total token supply enforced by mintAsset
+     * @param newMaxTotalSupply New supply cap (0 disables)
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function updateMaxTotalSupply(uint256 newMaxTotalSupply) external onlyOwner {
+        maxTotalSupply = newMaxTotalSupply;
+        emit MaxTotalSupplyUpdated(newMaxTotalSupply);
+    }
+
+    /**
+     * @dev Updates the lifetime cap on tokens minted to any single recipient
+     * @param newMaxLifetimePerRecipient New per-recipient cap (0 disables)
+     *
+     * The cap applies to lifetimeMinted, which includes mints made while it was disabled.
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function updateMaxLifetimePerRecipient(uint256 newMaxLifetimePerRecipient) external onlyOwner {
+        maxLifetimePerRecipient = newMaxLifetimePerRecipient;
+        emit MaxLifetimePerRecipientUpdated(newMaxLifetimePerRecipient);
+    }
+
+    /**
+     * @dev Updates the windowed cap on tokens minted to any single recipient
+     * @param maxPerWindow New per-recipient cap per window (0 disables)
+     * @param windowDuration Length of the window in seconds
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function updateRecipientWindowCap(uint256 maxPerWindow, uint256 windowDuration) external onlyOwner {
+        if (maxPerWindow != 0 && windowDuration == 0) revert InvalidWindow();
+        maxPerRecipientWindow = maxPerWindow;
+        recipientWindowDuration = windowDuration;
+        emit RecipientWindowCapUpdated(maxPerWindow, windowDuration);
+    }
+
+    /**
+     * @dev Grants or revokes fee exemption for a user
+     * @param user Address of the user
+     * @param exempt Whether the user bridges without fees
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setFeeExempt(address user, bool exempt) external onlyOwner {
+        if (user == address(0)) revert InvalidUserAddress();
+        if (feeExempt[user] != exempt) {
+            keyedConfigDigest ^= keccak256(abi.encode("feeExempt", user));
+        }
+        feeExempt[user] = exempt;
+        emit FeeExemptionUpdated(user, exempt);
+    }
+
+    /**
+     * @dev Pauses bridge operations
+     * Security: Only callable by owner (Oracle)
+     */
+    function pause() external onlyOwner {
+        _pause();
+        pausedAt = block.timestamp;
+    }
+
+    /**
+     * @dev Unpauses bridge operations
+     * Security: Only callable by owner (Oracle)
+     */
+    function unpause() external onlyOwner {
+        _unpause();
+    }
+
+    /**
+     * @dev Trips the kill switch, blocking bridging, minting and fee withdrawals
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Stays active until the dedicated reset path is used
+     */
+    function tripKillSwitch() external onlyOwner {
+        killSwitch.trip();
+    }
+
+    /**
+     * @dev Starts the delay after which the kill switch may be reset
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function proposeKillSwitchReset() external onlyOwner {
+        killSwitch.proposeReset();
+    }
+
+    /**
+     * @dev Re-enables token movement after the kill switch was tripped
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Always requires a proposal at least killSwitchResetDelay old
+     */
+    function resetKillSwitch() external onlyOwner {
+        killSwitch.reset();
+    }
+
+    /**
+     * @dev Updates the delay between proposing and applying a kill switch reset
+     * @param newKillSwitchResetDelay New delay in seconds, at most MAX_KILL_SWITCH_RESET_DELAY
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Cannot be changed while the kill switch is active, so the delay can't be skipped
+     * - Bounded so a reset can always be proposed without overflowing
+     */
+    function updateKillSwitchResetDelay(uint256 newKillSwitchResetDelay) external onlyOwner {
+        killSwitch.updateResetDelay(newKillSwitchResetDelay);
+    }
+
+    /**
+     * @dev Updates how long the bridge must stay paused before sunset actions
+     * @param newMinPauseDuration New cooling-off period in seconds (0 disables)
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function updateMinPauseDuration(uint256 newMinPauseDuration) external onlyOwner {
+        minPauseDuration = newMinPauseDuration;
+        emit MinPauseDurationUpdated(newMinPauseDuration);
+    }
+
+    /**
+     * @dev Updates the minimum interval between fee withdrawals
+     * @param newWithdrawCooldown New interval in seconds (0 disables)
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function updateWithdrawCooldown(uint256 newWithdrawCooldown) external onlyOwner {
+        withdrawCooldown = newWithdrawCooldown;
+        emit WithdrawCooldownUpdated(newWithdrawCooldown);
+    }
+
+    /**
+     * @dev Approves or revokes a treasury as a fee withdrawal destination
+     * @param treasury Address of the treasury
+     * @param approved Whether withdrawFees may pay it
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setApprovedTreasury(address treasury, bool approved) external onlyOwner {
+        if (treasury == address(0)) revert InvalidRecipient();
+        if (approvedTreasuries[treasury] != approved) {
+            keyedConfigDigest ^= keccak256(abi.encode("treasury", treasury));
+        }
+        approvedTreasuries[treasury] = approved;
+        emit TreasuryApprovalUpdated(treasury, approved);
+    }
+
+    /**
+     * @dev Enables or disables the treasury allowlist for fee withdrawals
+     * @param enabled Whether withdrawFees only pays approved treasuries
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setTreasuryAllowlistEnabled(bool enabled) external onlyOwner {
+        treasuryAllowlistEnabled = enabled;
+        emit TreasuryAllowlistToggled(enabled);
+    }
+
+    /**
+     * @dev Adds or removes a destination chain from the chain allowlist
+     * @param chain Exact destination chain identifier
+     * @param allowed Whether receiveAsset accepts bridges to it while the allowlist is enabled
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setChainAllowed(string calldata chain, bool allowed) external onlyOwner {
+        chainRegistry.setAllowed(chain, allowed);
+    }
+
+    /**
+     * @dev Enables or disables the destination chain allowlist
+     * @param enabled Whether receiveAsset only accepts allowed chains
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setChainAllowlistEnabled(bool enabled) external onlyOwner {
+        chainRegistry.setAllowlistEnabled(enabled);
+    }
+
+    /**
+     * @dev Permanently gives up the bridge's ability to mint when sunsetting
+     *
+     * Only stops minting through this bridge. TokenManager.mint and burnFrom
+     * still accept calls from the token owner (the DAO), so supply stays under
+     * DAO control after the bridge is sunset.
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Requires the bridge to have been paused for at least minPauseDuration
+     * - Irreversible
+     */
+    function relinquishMinting() external onlyOwner whenPaused {
+        if (block.timestamp < pausedAt + minPauseDuration) revert PauseTooRecent();
+        if (mintingRelinquished) revert MintingDisabled();
+        mintingRelinquished = true;
+        emit MintingRelinquished();
     }
 
     /**
@@ -216,19 +1352,214 @@ contract Bridge is Ownable, Pausable {
      *
      * Security:
      * - Only callable by owner (Oracle)
+     * - Rejects calls within withdrawCooldown of the previous withdrawal
+     * - Only pays approved treasuries while the allowlist is enabled
      * - Protected against reentrancy by transfer pattern
      * - Emits event for tracking
      */
-    function withdrawFees(address to) external onlyOwner {
-        require(to != address(0), "Invalid recipient");
+    function withdrawFees(address to) external onlyOwner whenNotKilled {
+        if (to == address(0)) revert InvalidRecipient();
+        if (withdrawCooldown != 0 && lastWithdrawAt != 0) {
+            if (block.timestamp < lastWithdrawAt + withdrawCooldown) revert WithdrawTooFrequent();
+        }
+        _requireApprovedTreasury(to);
+        lastWithdrawAt = block.timestamp;
         IERC20 token = IERC20(tokenAddress);
         address thisAddress = address(this);
-        uint256 balance = token.balanceOf(thisAddress);
-        require(balance != 0, "No fees to withdraw");
-        require(token.transfer(to, balance), "Fee withdrawal failed");
+        // Fees earmarked for chain-specific recipients are withdrawn separately
+        uint256 balance = token.balanceOf(thisAddress) - chainRegistry.earmarkedFees;
+        if (balance == 0) revert NoFeesToWithdraw();
+        if (!token.transfer(to, balance)) revert FeeWithdrawalFailed();
         emit FeesWithdrawn(to, balance);
     }
 
+    /**
+     * @dev Updates the maximum number of chains that can hold a configuration
+     * @param newMaxChainConfigs New cap (0 disables)
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function updateMaxChainConfigs(uint256 newMaxChainConfigs) external onlyOwner {
+        chainRegistry.updateMaxConfigs(newMaxChainConfigs);
+    }
+
+    /**
+     * @dev Removes a chain's configuration, freeing a slot under maxChainConfigs
+     * @param chain Destination chain identifier
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Chain fees must be withdrawn first so they are not stranded
+     * - A paused chain must be resumed first so clearing cannot lift the pause
+     * - Does not remove the chain from the chain allowlist
+     */
+    function clearChainConfig(string calldata chain) external onlyOwner {
+        chainRegistry.clearConfig(chain);
+    }
+
+    /**
+     * @dev Sets the token decimals used on a destination chain
+     * @param chain Destination chain identifier
+     * @param remoteDecimals Token decimals on that chain
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setChainRemoteDecimals(string calldata chain, uint8 remoteDecimals) external onlyOwner {
+        chainRegistry.setRemoteDecimals(chain, remoteDecimals);
+    }
+
+    /**
+     * @dev Sets the expected relay time for a destination chain
+     * @param chain Destination chain identifier
+     * @param expectedRelaySeconds Expected time for a bridge to that chain to be relayed
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setChainRelayTimeout(string calldata chain, uint64 expectedRelaySeconds) external onlyOwner {
+        chainRegistry.setRelayTimeout(chain, expectedRelaySeconds);
+    }
+
+    /**
+     * @dev Pauses or resumes bridging to a single destination chain
+     * @param chain Destination chain identifier
+     * @param chainPaused Whether receiveAsset rejects bridges to that chain
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Does not register the chain, so an incident pause is never blocked by maxChainConfigs
+     * - Aliases of the chain are only rejected while the chain allowlist is enabled
+     */
+    function setChainPaused(string calldata chain, bool chainPaused) external onlyOwner {
+        chainRegistry.setPaused(chain, chainPaused);
+    }
+
+    /**
+     * @dev Sets the smallest unit a destination chain can mint
+     * @param chain Destination chain identifier
+     * @param remoteGranularity Required divisor of the amount after fee (0 disables)
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setChainGranularity(string calldata chain, uint256 remoteGranularity) external onlyOwner {
+        chainRegistry.setGranularity(chain, remoteGranularity);
+    }
+
+    /**
+     * @dev Sets the minimum amount delivered for bridges to a destination chain
+     * @param chain Destination chain identifier
+     * @param minAmount Minimum amount after fees, applied on top of minBridgedAmount (0 disables)
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setChainMinAmount(string calldata chain, uint256 minAmount) external onlyOwner {
+        chainRegistry.setMinAmount(chain, minAmount);
+    }
+
+    /**
+     * @dev Configures automatic fee collection
+     * @param threshold Shared fee balance that triggers a sweep (0 disables)
+     * @param recipient Address receiving swept fees
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Recipient must be an approved treasury while the allowlist is enabled
+     */
+    function setFeeSweep(uint256 threshold, address recipient) external onlyOwner {
+        if (threshold != 0 && recipient == address(0)) revert InvalidRecipient();
+        if (threshold != 0) {
+            _requireApprovedTreasury(recipient);
+        }
+        feeSweepThreshold = threshold;
+        feeSweepRecipient = recipient;
+        emit FeeSweepUpdated(threshold, recipient);
+    }
+
+    /**
+     * @dev Switches the flat operation fee to the native currency
+     * @param fee Wei each bridge must attach (0 reverts to operationFee in tokens)
+     * @param collector Address receiving the native fees
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function setNativeOperationFee(uint256 fee, address collector) external onlyOwner {
+        if (fee != 0 && collector == address(0)) revert InvalidRecipient();
+        nativeOperationFee = fee;
+        nativeFeeCollector = collector;
+        emit NativeOperationFeeUpdated(fee, collector);
+    }
+
+    /**
+     * @dev Pays the native fees accrued by bridges to the native fee collector
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Funds can only go to the current nativeFeeCollector
+     * - A collector that rejects the transfer keeps the fees accrued until it is replaced
+     */
+    function withdrawNativeFees() external onlyOwner whenNotKilled {
+        address collector = nativeFeeCollector;
+        if (collector == address(0)) revert InvalidRecipient();
+        uint256 amount = accruedNativeFees;
+        if (amount == 0) revert NoFeesToWithdraw();
+        accruedNativeFees = 0;
+        (bool sent, ) = collector.call{value: amount}("");
+        if (!sent) revert NativeFeeTransferFailed();
+        emit NativeFeesWithdrawn(collector, amount);
+    }
+
+    /**
+     * @dev Withdraws the fees accrued by bridges to a chain to its fee recipient
+     * @param chain Destination chain identifier
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Funds can only go to the chain's configured recipient
+     * - That recipient must be an approved treasury while the allowlist is enabled
+     */
+    function withdrawChainFees(string memory chain) external onlyOwner whenNotKilled {
+        bytes32 chainKey = _chainKey(chain);
+        address recipient = chainRegistry.configs[chainKey].feeRecipient;
+        if (recipient == address(0)) revert NoChainFeeRecipient();
+        _requireApprovedTreasury(recipient);
+        uint256 amount = chainRegistry.collectedFees[chainKey];
+        if (amount == 0) revert NoFeesToWithdraw();
+
+        chainRegistry.collectedFees[chainKey] = 0;
+        chainRegistry.earmarkedFees -= amount;
+        if (!IERC20(tokenAddress).transfer(recipient, amount)) revert FeeWithdrawalFailed();
+        emit ChainFeesWithdrawn(chain, recipient, amount);
+    }
+
+    /**
+     * @dev Sets the recipient of fees from bridges to a destination chain
+     * @param chain Destination chain identifier
+     * @param recipient Fee recipient (zero sends future fees to the shared pool)
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Recipient must be an approved treasury while the allowlist is enabled
+     * - Cannot be cleared while the chain still holds unwithdrawn fees
+     */
+    function setChainFeeRecipient(string calldata chain, address recipient) external onlyOwner {
+        _requireApprovedTreasury(recipient);
+        chainRegistry.setFeeRecipient(chain, recipient);
+    }
+
+    /**
+     * @dev Configures several destination chains in one transaction
+     * @param entries Settings for each chain, applied in order
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Bounded by MAX_CHAIN_BATCH; any invalid entry reverts the whole batch
+     */
+    function configureChains(BridgeAdminLib.ChainSetup[] calldata entries) external onlyOwner {
+        for (uint256 i = 0; i < entries.length; i++) {
+            _requireApprovedTreasury(entries[i].feeRecipient);
+        }
+        chainRegistry.configure(entries);
+    }
+
     /**
      * @dev Updates the offchain processor address
      * @param newOffchainProcessor New processor address
@@ -236,9 +1567,542 @@ contract Bridge is Ownable, Pausable {
      * Security: Only callable by owner (Oracle)
      */
     function changeOffchain(address newOffchainProcessor) external onlyOwner {
-        require(newOffchainProcessor != address(0), "Invalid processor address");
+        if (newOffchainProcessor == address(0)) revert InvalidProcessorAddress();
+        if (newOffchainProcessor == backupProcessor) revert BackupMatchesPrimary();
         address oldProcessor = offchainProcessor;
         offchainProcessor = newOffchainProcessor;
         emit OffchainProcessorChanged(oldProcessor, newOffchainProcessor);
     }
+
+    /**
+     * @dev Packs the outcome of _receiveAsset into the returned confirmation
+     */
+    function _receiveResult(
+        uint256 bridgeId,
+        uint256 fee,
+        uint256 amountAfterFee
+    ) internal view returns (ReceiveResult memory) {
+        return ReceiveResult({
+            bridgeId: bridgeId,
+            fee: fee,
+            amountAfterFee: amountAfterFee,
+            blockNumber: block.number
+        });
+    }
+
+    /**
+     * @dev Transfers tokens from a user to the bridge
+     * @return received Amount that actually arrived, net of any token transfer fee
+     */
+    function _pullTokens(TokenManager token, address user, uint256 amount) internal returns (uint256 received) {
+        uint256 balanceBefore = token.balanceOf(address(this));
+        if (!token.transferFrom(user, address(this), amount)) revert TransferFailed();
+        received = token.balanceOf(address(this)) - balanceBefore;
+    }
+
+    /**
+     * @dev Sends the shared fee pool to the sweep recipient once it reaches the threshold
+     */
+    function _sweepFeesIfNeeded() internal {
+        if (feeSweepThreshold == 0) {
+            return;
+        }
+        IERC20 token = IERC20(tokenAddress);
+        uint256 balance = token.balanceOf(address(this)) - chainRegistry.earmarkedFees;
+        // Skip rather than revert, so a recipient unapproved later cannot block user bridges
+        if (treasuryAllowlistEnabled && !approvedTreasuries[feeSweepRecipient]) {
+            return;
+        }
+        if (balance >= feeSweepThreshold) {
+            if (!token.transfer(feeSweepRecipient, balance)) revert FeeSweepFailed();
+            emit FeesWithdrawn(feeSweepRecipient, balance);
+        }
+    }
+
+    /**
+     * @dev Reverts when the treasury allowlist is on and a nonzero fee destination is not approved
+     */
+    function _requireApprovedTreasury(address destination) internal view {
+        if (treasuryAllowlistEnabled && destination != address(0)) {
+            if (!approvedTreasuries[destination]) revert UnapprovedDestination();
+        }
+    }
+
+    /**
+     * @dev Returns the native operation fee a bridge must attach
+     * @param user Address being bridged for; fee-exempt users must attach nothing
+     */
+    function _nativeFeeDue(address user) internal view returns (uint256) {
+        return _isFeeExempt(user) ? 0 : nativeOperationFee;
+    }
+
+    /**
+     * @dev Whether an amount can be minted exactly on the destination chain
+     */
+    function _isRemoteDivisible(BridgeAdminLib.ChainConfig storage config, uint256 amountAfterFee) internal view returns (bool) {
+        uint256 granularity = config.remoteGranularity;
+        return granularity == 0 || amountAfterFee % granularity == 0;
+    }
+
+    /**
+     * @dev Reports a supply change together with the token's supply after it
+     */
+    function _emitSupplyDelta(int256 delta) internal {
+        emit SupplyDelta(tokenAddress, delta, IERC20(tokenAddress).totalSupply());
+    }
+
+    /**
+     * @dev Whether an event bit is not muted by eventMask
+     */
+    function _eventEnabled(uint16 eventBit) internal view returns (bool) {
+        return eventMask & eventBit == 0;
+    }
+
+    /**
+     * @dev Emits the bridged amount in the destination chain's decimals when configured
+     */
+    function _emitScaledAmount(
+        address user,
+        string memory destinationChain,
+        BridgeAdminLib.ChainConfig storage config,
+        uint256 amountAfterFee
+    ) internal {
+        if (!config.hasRemoteDecimals) {
+            return;
+        }
+        uint8 remoteDecimals = config.remoteDecimals;
+        uint256 scaledAmount;
+        if (remoteDecimals >= tokenDecimals) {
+            scaledAmount = amountAfterFee * 10 ** (remoteDecimals - tokenDecimals);
+        } else {
+            scaledAmount = amountAfterFee / 10 ** (tokenDecimals - remoteDecimals);
+        }
+        emit BridgeAmountScaled(user, destinationChain, scaledAmount, remoteDecimals);
+    }
+
+    /**
+     * @dev Returns the storage key for a chain identifier
+     */
+    function _chainKey(string memory chain) internal pure returns (bytes32) {
+        return keccak256(bytes(chain));
+    }
+
+    /**
+     * @dev Whether a user bridges without token or native fees
+     */
+    function _isFeeExempt(address user) internal view returns (bool) {
+        return user == owner() || feeExempt[user];
+    }
+
+    /**
+     * @dev Calculates the total fee charged to a user for bridging an amount
+     * @param user Address initiating the bridge
+     * @param amount Amount of tokens to bridge
+     * @return Total fee, zero for the owner and fee-exempt users
+     */
+    function _calculateFee(address user, uint256 amount) internal view returns (uint256) {
+        if (_isFeeExempt(user)) {
+            return 0;
+        }
+        uint256 fee = _uncappedFee(amount);
+        uint256 maxFee = feeSchedule.maxTotalFee;
+        if (maxFee != 0 && fee > maxFee) {
+            return maxFee;
+        }
+        return fee;
+    }
+
+    /**
+     * @dev Returns the transfer fee plus the token operation fee, before maxTotalFee is applied
+     */
+    function _uncappedFee(uint256 amount) internal view returns (uint256) {
+        uint256 flatFee = nativeOperationFee != 0 ? 0 : feeSchedule.operationFee;
+        return (amount * currentTransferFee()) / feeSchedule.precision + flatFee;
+    }
+
+    /**
+     * @dev Whether maxTotalFee replaces the computed fee for an amount
+     */
+    function _isFeeCapped(uint256 amount) internal view returns (bool) {
+        return feeSchedule.maxTotalFee != 0 && _uncappedFee(amount) > feeSchedule.maxTotalFee;
+    }
+
+    /**
+     * @dev Returns a user's window volume after adding an amount
+     * @param user Address of the user
+     * @param amount Amount being bridged
+     * @return Volume for the current window, restarting from zero once the window has elapsed
+     */
+    function _userVolumeAfter(address user, uint256 amount) internal view returns (uint256) {
+        if (block.timestamp >= userWindowStart[user] + DAILY_WINDOW) {
+            return amount;
+        }
+        return userDailyVolume[user] + amount;
+    }
+
+    /**
+     * @dev Adds a bridge to the window totals, first summarizing the previous window if it has ended
+     * @param amount Amount being bridged
+     */
+    function _recordDailyVolume(uint256 amount) internal {
+        if (block.timestamp >= dailyWindowStart + DAILY_WINDOW) {
+            if (dailyWindowBridgeCount != 0) {
+                emit DailyVolume(dailyWindowStart, dailyWindowVolume, dailyWindowBridgeCount);
+            }
+            dailyWindowStart = block.timestamp;
+            dailyWindowVolume = 0;
+            dailyWindowBridgeCount = 0;
+        }
+        dailyWindowVolume += amount;
+        dailyWindowBridgeCount += 1;
+    }
+
+    /**
+     * @dev Mirrors the receiveAsset checks and returns the first failing one
+     */
+    function _rejectionReason(
+        address user,
+        uint256 amount,
+        string memory destinationChain,
+        address destinationAddress
+    ) internal view returns (uint8) {
+        // Same order as the whenNotPaused and whenNotKilled modifiers
+        if (paused()) return REJECT_PAUSED;
+        if (killSwitch.tripped) return REJECT_KILLED;
+        if (amount == 0) return REJECT_ZERO_AMOUNT;
+        if (bytes(destinationChain).length == 0) return REJECT_INVALID_CHAIN;
+        if (destinationAddress == address(0)) return REJECT_INVALID_DESTINATION;
+        bytes32 chainKey = _chainKey(destinationChain);
+        BridgeAdminLib.ChainConfig storage chainConfig = chainRegistry.configs[chainKey];
+        if (chainConfig.paused) return REJECT_CHAIN_PAUSED;
+        if (chainRegistry.allowlistEnabled && !chainRegistry.allowed[chainKey]) return REJECT_CHAIN_NOT_ALLOWED;
+        if (minUserNativeBalance != 0 && user.balance < minUserNativeBalance) return REJECT_LOW_NATIVE_BALANCE;
+        if (IERC20(tokenAddress).allowance(user, address(this)) < amount) return REJECT_INSUFFICIENT_ALLOWANCE;
+
+        uint256 totalFee = _calculateFee(user, amount);
+        if (totalFee >= amount) return REJECT_FEE_EXCEEDS_AMOUNT;
+        if (amount - totalFee < minBridgedAmount) return REJECT_AMOUNT_AFTER_FEE_TOO_SMALL;
+        if (maxDailyPerUser != 0 && _userVolumeAfter(user, amount) > maxDailyPerUser) return REJECT_USER_DAILY_LIMIT;
+        if (!_isRemoteDivisible(chainConfig, amount - totalFee)) return REJECT_NOT_DIVISIBLE;
+        if (amount - totalFee < chainConfig.minAmount) return REJECT_BELOW_CHAIN_MINIMUM;
+
+        return REJECT_NONE;
+    }
+
+    /**
+     * @dev Sets the backup processor that can also mint
+     * @param newBackupProcessor New backup processor address (zero removes it)
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function changeBackupProcessor(address newBackupProcessor) external onlyOwner {
+        if (newBackupProcessor == offchainProcessor) revert BackupMatchesPrimary();
+        address oldProcessor = backupProcessor;
+        backupProcessor = newBackupProcessor;
+        emit BackupProcessorChanged(oldProcessor, newBackupProcessor);
+    }
+
+    /**
+     * @dev Promotes the backup processor to primary and clears the backup slot
+     *
+     * Security: Only callable by owner (Oracle)
+     */
+    function promoteBackupProcessor() external onlyOwner {
+        if (backupProcessor == address(0)) revert NoBackupProcessor();
+        address oldProcessor = offchainProcessor;
+        offchainProcessor = backupProcessor;
+        backupProcessor = address(0);
+        emit OffchainProcessorChanged(oldProcessor, offchainProcessor);
+        emit BackupProcessorChanged(offchainProcessor, address(0));
+    }
+
+    /**
+     * @dev Returns the stored transfer fee, in units of feePrecision
+     */
+    function transferFee() external view returns (uint256) {
+        return feeSchedule.transferFee;
+    }
+
+    /**
+     * @dev Returns the flat fee in tokens
+     */
+    function operationFee() external view returns (uint256) {
+        return feeSchedule.operationFee;
+    }
+
+    /**
+     * @dev Returns the divisor applied to transferFee
+     */
+    function feePrecision() external view returns (uint256) {
+        return feeSchedule.precision;
+    }
+
+    /**
+     * @dev Returns the absolute ceiling on transfer + operation fee (0 disables)
+     */
+    function maxTotalFee() external view returns (uint256) {
+        return feeSchedule.maxTotalFee;
+    }
+
+    /**
+     * @dev Returns the governance floor on the transfer fee, in basis points
+     */
+    function minFeeBasisPoints() external view returns (uint256) {
+        return feeSchedule.minFeeBasisPoints;
+    }
+
+    /**
+     * @dev Returns the delay before transfer fee changes take effect
+     */
+    function feeChangeDelay() external view returns (uint256) {
+        return feeSchedule.changeDelay;
+    }
+
+    /**
+     * @dev Returns the scheduled transfer fee, 0 when none is scheduled
+     */
+    function pendingTransferFee() external view returns (uint256) {
+        return feeSchedule.pendingTransferFee;
+    }
+
+    /**
+     * @dev Returns when the scheduled transfer fee applies, 0 when none is scheduled
+     */
+    function pendingFeeEffectiveAt() external view returns (uint256) {
+        return feeSchedule.pendingEffectiveAt;
+    }
+
+    /**
+     * @dev Whether the kill switch is tripped
+     */
+    function killSwitchTripped() external view returns (bool) {
+        return killSwitch.tripped;
+    }
+
+    /**
+     * @dev Returns the seconds between proposing and applying a kill switch reset
+     */
+    function killSwitchResetDelay() external view returns (uint256) {
+        return killSwitch.resetDelay;
+    }
+
+    /**
+     * @dev Returns when a proposed kill switch reset may be applied, 0 if none proposed
+     */
+    function killSwitchResetReadyAt() external view returns (uint256) {
+        return killSwitch.resetReadyAt;
+    }
+
+    /**
+     * @dev Returns the configuration of a destination chain
+     * @param chainKey keccak256 of the exact chain identifier
+     */
+    function chainConfigs(bytes32 chainKey) external view returns (BridgeAdminLib.ChainConfig memory) {
+        return chainRegistry.configs[chainKey];
+    }
+
+    /**
+     * @dev Returns the number of chains holding a configuration
+     */
+    function chainConfigCount() external view returns (uint256) {
+        return chainRegistry.count;
+    }
+
+    /**
+     * @dev Returns the cap on chains holding a configuration (0 disables)
+     */
+    function maxChainConfigs() external view returns (uint256) {
+        return chainRegistry.maxConfigs;
+    }
+
+    /**
+     * @dev Returns the fees earmarked for a destination chain's fee recipient
+     * @param chainKey keccak256 of the exact chain identifier
+     */
+    function collectedFeesByChain(bytes32 chainKey) external view returns (uint256) {
+        return chainRegistry.collectedFees[chainKey];
+    }
+
+    /**
+     * @dev Returns the sum of collectedFeesByChain, excluded from withdrawFees
+     */
+    function earmarkedFees() external view returns (uint256) {
+        return chainRegistry.earmarkedFees;
+    }
+
+    /**
+     * @dev Whether receiveAsset only accepts allowed chains
+     */
+    function chainAllowlistEnabled() external view returns (bool) {
+        return chainRegistry.allowlistEnabled;
+    }
+
+    /**
+     * @dev Whether a destination chain is on the chain allowlist
+     * @param chainKey keccak256 of the exact chain identifier
+     */
+    function allowedChains(bytes32 chainKey) external view returns (bool) {
+        return chainRegistry.allowed[chainKey];
+    }
+
+    /**
+     * @dev Returns the commonly read bridge configuration in a single call
+     * @return summary Snapshot of the current configuration
+     */
+    function getBridgeSummary() external view returns (BridgeSummary memory summary) {
+        summary = BridgeSummary({
+            tokenAddress: tokenAddress,
+            tokenDecimals: tokenDecimals,
+            transferFee: currentTransferFee(),
+            feePrecision: feeSchedule.precision,
+            operationFee: feeSchedule.operationFee,
+            nativeOperationFee: nativeOperationFee,
+            nativeFeeCollector: nativeFeeCollector,
+            maxTotalFee: feeSchedule.maxTotalFee,
+            minBridgedAmount: minBridgedAmount,
+            maxTotalSupply: maxTotalSupply,
+            offchainProcessor: offchainProcessor,
+            paused: paused()
+        });
+    }
+
+    /**
+     * @dev Returns a hash over the full bridge configuration
+     * @return Hash of the abi-encoded getBridgeConfig result
+     *
+     * Monitoring can compare this against an expected value to detect drift.
+     * The hash is the contract: getBridgeConfig returns every field it covers,
+     * and ConfigSnapshot only carries the most commonly checked ones.
+     */
+    function configHash() public view returns (bytes32) {
+        return keccak256(abi.encode(getBridgeConfig()));
+    }
+
+    /**
+     * @dev Returns the full versioned configuration covered by configHash
+     * @return config Current configuration, a matured scheduled fee shown as already applied
+     *
+     * Per-chain configs, allowed chains, approved treasuries and fee exemptions are
+     * readable through their mappings and enter the hash as keyedConfigDigest.
+     */
+    function getBridgeConfig() public view returns (BridgeConfig memory config) {
+        config.version = CONFIG_SNAPSHOT_VERSION;
+        config.tokenAddress = tokenAddress;
+        config.tokenDecimals = tokenDecimals;
+        config.owner = owner();
+        config.fees = _feeConfig();
+        config.minting = _mintingConfig();
+        config.operations = _operationsConfig();
+        config.keyedConfigDigest = keyedConfigDigest ^ chainRegistry.digest;
+    }
+
+    /**
+     * @dev Collects the settings that decide what fees cost and where they go
+     */
+    function _feeConfig() internal view returns (FeeConfig memory fees) {
+        fees.transferFee = currentTransferFee();
+        fees.feePrecision = feeSchedule.precision;
+        fees.operationFee = feeSchedule.operationFee;
+        fees.maxTotalFee = feeSchedule.maxTotalFee;
+        fees.minFeeBasisPoints = feeSchedule.minFeeBasisPoints;
+        fees.feeChangeDelay = feeSchedule.changeDelay;
+        if (feeSchedule.pendingEffectiveAt != 0 && block.timestamp < feeSchedule.pendingEffectiveAt) {
+            fees.pendingTransferFee = feeSchedule.pendingTransferFee;
+            fees.pendingFeeEffectiveAt = feeSchedule.pendingEffectiveAt;
+        }
+        fees.nativeOperationFee = nativeOperationFee;
+        fees.nativeFeeCollector = nativeFeeCollector;
+        fees.feeSweepThreshold = feeSweepThreshold;
+        fees.feeSweepRecipient = feeSweepRecipient;
+        fees.treasuryAllowlistEnabled = treasuryAllowlistEnabled;
+        fees.withdrawCooldown = withdrawCooldown;
+    }
+
+    /**
+     * @dev Collects the settings that grant or limit minting
+     */
+    function _mintingConfig() internal view returns (MintingConfig memory minting) {
+        minting.offchainProcessor = offchainProcessor;
+        minting.backupProcessor = backupProcessor;
+        minting.postMintHook = postMintHook;
+        minting.revertOnHookFailure = revertOnHookFailure;
+        minting.maxTotalSupply = maxTotalSupply;
+        minting.maxLifetimePerRecipient = maxLifetimePerRecipient;
+        minting.maxPerRecipientWindow = maxPerRecipientWindow;
+        minting.recipientWindowDuration = recipientWindowDuration;
+    }
+
+    /**
+     * @dev Collects the pause, kill switch, bridge limit and event settings
+     */
+    function _operationsConfig() internal view returns (OperationsConfig memory operations) {
+        operations.paused = paused();
+        operations.mintingRelinquished = mintingRelinquished;
+        operations.minPauseDuration = minPauseDuration;
+        operations.killSwitchTripped = killSwitch.tripped;
+        operations.killSwitchResetDelay = killSwitch.resetDelay;
+        operations.killSwitchResetReadyAt = killSwitch.resetReadyAt;
+        operations.minBridgedAmount = minBridgedAmount;
+        operations.minUserNativeBalance = minUserNativeBalance;
+        operations.maxDailyPerUser = maxDailyPerUser;
+        operations.maxChainConfigs = chainRegistry.maxConfigs;
+        operations.chainAllowlistEnabled = chainRegistry.allowlistEnabled;
+        operations.eventMask = eventMask;
+        operations.verboseEvents = verboseEvents;
+        operations.burnReceiptsEnabled = burnReceiptsEnabled;
+        operations.genericMessagesEnabled = genericMessagesEnabled;
+        operations.minConfirmations = minConfirmations;
+        operations.localChainName = localChainName;
+    }
+
+    /**
+     * @dev Emits a snapshot of the current configuration and its hash
+     *
+     * Security: Read-only with respect to state, callable by anyone
+     */
+    function dumpConfig() external {
+        _emitConfigSnapshot();
+    }
+
+    /**
+     * @dev Pauses the bridge and records a forensic snapshot in one call
+     *
+     * Security:
+     * - Only callable by owner (Oracle)
+     * - Leaves an existing pause and its pausedAt untouched
+     */
+    function emergencySnapshot() external onlyOwner {
+        if (!paused()) {
+            _pause();
+            pausedAt = block.timestamp;
+        }
+        _emitConfigSnapshot();
+
+        IERC20 token = IERC20(tokenAddress);
+        emit EmergencySnapshot(
+            pausedAt,
+            token.balanceOf(address(this)),
+            chainRegistry.earmarkedFees,
+            token.totalSupply(),
+            circulatingOnRemote
+        );
+    }
+
+    /**
+     * @dev Emits the current configuration and its hash
+     */
+    function _emitConfigSnapshot() internal {
+        emit ConfigSnapshot(
+            CONFIG_SNAPSHOT_VERSION,
+            tokenAddress,
+            currentTransferFee(),
+            feeSchedule.precision,
+            feeSchedule.operationFee,
+            offchainProcessor,
+            backupProcessor,
+            owner(),
+            paused(),
+            configHash()
+        );
+    }
 }
\ No newline at end of file
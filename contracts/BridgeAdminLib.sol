@@ -0,0 +1,515 @@
+// SPDX-License-Identifier: GPL-3.0
+pragma solidity ^0.8.21;
+
+import {IBridgeAdmin} from "./interfaces/IBridgeAdmin.sol";
+
+/**
+ * @title BridgeAdminLib
+ * @dev Fee schedule, kill switch and destination chain administration for the Bridge
+ *
+ * Deployed once and linked into the Bridge, whose owner-only setters delegate
+ * here so the Bridge stays under the EIP-170 contract size limit. Events and
+ * errors are declared in IBridgeAdmin so they remain part of the Bridge ABI.
+ *
+ * Security considerations:
+ * - External functions run via delegatecall against the Bridge's storage
+ * - Access control stays with the calling Bridge function (onlyOwner)
+ * - Internal functions are used on the bridge path, avoiding a delegatecall per bridge
+ */
+library BridgeAdminLib {
+    uint256 private constant FEE_DENOMINATOR = 10000;
+    uint256 private constant MAX_TRANSFER_FEE = 1000; // 10%
+    uint256 private constant MAX_OPERATION_FEE = 1000 * 10 ** 18; // 1000 tokens
+    uint256 private constant MAX_FEE_PRECISION = 1000000; // hundredths of a basis point
+    uint256 private constant MAX_FEE_CHANGE_DELAY = 7 days;
+    uint256 private constant DEFAULT_KILL_SWITCH_RESET_DELAY = 1 days;
+    uint256 private constant MAX_KILL_SWITCH_RESET_DELAY = 30 days;
+
+    // Same bound the Bridge applies to its own token, keeping decimal scaling within uint256
+    uint8 private constant MAX_TOKEN_DECIMALS = 18;
+
+    // Upper bound on chains configured in one configureChains call
+    uint256 private constant MAX_CHAIN_BATCH = 20;
+
+    // Transfer and operation fees, with delayed transfer fee changes so in-flight quotes keep their fee
+    struct FeeSchedule {
+        uint256 transferFee;        // fraction of precision (e.g., 100 = 1% at the default precision)
+        uint256 operationFee;       // flat fee in tokens
+        uint256 precision;          // divisor applied to transferFee, FEE_DENOMINATOR by default
+        uint256 maxTotalFee;        // absolute ceiling on transfer + operation fee (0 disables)
+        uint256 minFeeBasisPoints;  // governance floor on the transfer fee, in basis points
+        uint256 changeDelay;
+        uint256 pendingTransferFee;
+        uint256 pendingEffectiveAt; // 0 when no change is scheduled
+    }
+
+    // Emergency stop for all token movement, unlike pause not toggled casually
+    struct KillSwitch {
+        bool tripped;
+        uint256 resetDelay;         // seconds between proposing and applying a reset
+        uint256 resetReadyAt;       // when a proposed reset may be applied, 0 if none proposed
+    }
+
+    // Per-destination-chain settings, keyed by keccak256 of the exact chain identifier.
+    // A respelling of a chain has its own (empty) entry, so enable the chain allowlist to stop
+    // its pause, minimum, granularity and fee recipient from being skipped that way.
+    struct ChainConfig {
+        address feeRecipient;        // receives fees from bridges to this chain (zero uses withdrawFees)
+        uint8 remoteDecimals;        // token decimals on the destination chain
+        bool hasRemoteDecimals;      // whether remoteDecimals has been configured
+        uint64 expectedRelaySeconds; // relay ETA shown to users by clients
+        bool registered;             // counted towards the registry's count
+        bool paused;                 // blocks bridges to this chain during an incident there
+        uint256 remoteGranularity;   // amount after fee must be a multiple of this (0 disables)
+        uint256 minAmount;           // chain-specific minimum delivered after fees (0 disables)
+    }
+
+    // One entry of a configureChains batch
+    struct ChainSetup {
+        string chain;
+        address feeRecipient;
+        uint64 expectedRelaySeconds;
+        uint256 remoteGranularity;
+        uint256 minAmount;
+        uint8 remoteDecimals;
+        bool hasRemoteDecimals;      // false leaves the chain's remote decimals unchanged
+    }
+
+    struct ChainRegistry {
+        mapping(bytes32 => ChainConfig) configs;
+        uint256 count;
+        uint256 maxConfigs;          // 0 disables the cap
+        mapping(bytes32 => uint256) collectedFees;
+        uint256 earmarkedFees;       // sum of collectedFees, excluded from withdrawFees
+        // Destination chains receiveAsset accepts while the allowlist is enabled
+        bool allowlistEnabled;
+        mapping(bytes32 => bool) allowed;
+        // XOR of the hashes of all chain configs and allowed chains, so configHash covers them
+        bytes32 digest;
+    }
+
+    /**
+     * @dev Sets the initial fees and the default fee precision
+     * @param self Fee schedule to initialize
+     * @param transferFee Transfer fee in basis points
+     * @param operationFee Flat fee in tokens
+     */
+    function initialize(FeeSchedule storage self, uint256 transferFee, uint256 operationFee) internal {
+        if (transferFee > MAX_TRANSFER_FEE) revert IBridgeAdmin.TransferFeeTooHigh();
+        if (operationFee > MAX_OPERATION_FEE) revert IBridgeAdmin.OperationFeeTooHigh();
+        self.transferFee = transferFee;
+        self.operationFee = operationFee;
+        self.precision = FEE_DENOMINATOR;
+    }
+
+    /**
+     * @dev Sets the default kill switch reset delay
+     * @param self Kill switch to initialize
+     */
+    function initialize(KillSwitch storage self) internal {
+        self.resetDelay = DEFAULT_KILL_SWITCH_RESET_DELAY;
+    }
+
+    /**
+     * @dev Changes the transfer fee, or schedules the change when a delay is set
+     * @param self Fee schedule
+     * @param newFee New fee in units of the fee precision
+     * @param feeEventsEnabled Whether FeeUpdated is emitted (not muted by the event mask)
+     *
+     * A change still scheduled is cancelled and replaced.
+     */
+    function updateTransferFee(FeeSchedule storage self, uint256 newFee, bool feeEventsEnabled) external {
+        if (newFee > _maxTransferFee(self.precision)) revert IBridgeAdmin.FeeTooHigh();
+        if (newFee < _minTransferFee(self, self.precision)) revert IBridgeAdmin.FeeTooLow();
+        applyPendingTransferFee(self, feeEventsEnabled);
+        if (self.pendingEffectiveAt != 0) {
+            emit IBridgeAdmin.FeeChangeCancelled(self.pendingTransferFee, self.pendingEffectiveAt);
+            self.pendingTransferFee = 0;
+            self.pendingEffectiveAt = 0;
+        }
+        if (self.changeDelay == 0) {
+            self.transferFee = newFee;
+            if (feeEventsEnabled) {
+                emit IBridgeAdmin.FeeUpdated(newFee, self.operationFee);
+            }
+        } else {
+            uint256 effectiveAt = block.timestamp + self.changeDelay;
+            self.pendingTransferFee = newFee;
+            self.pendingEffectiveAt = effectiveAt;
+            emit IBridgeAdmin.TransferFeeScheduled(newFee, effectiveAt);
+        }
+    }
+
+    /**
+     * @dev Updates the delay before transfer fee changes take effect
+     * @param self Fee schedule
+     * @param newDelay New delay in seconds (0 applies changes immediately)
+     */
+    function updateFeeChangeDelay(FeeSchedule storage self, uint256 newDelay) external {
+        if (newDelay > MAX_FEE_CHANGE_DELAY) revert IBridgeAdmin.DelayTooLong();
+        self.changeDelay = newDelay;
+        emit IBridgeAdmin.FeeChangeDelayUpdated(newDelay);
+    }
+
+    /**
+     * @dev Switches the transfer fee divisor, restating the fee at the new precision
+     * @param self Fee schedule
+     * @param newPrecision New divisor, a multiple of 10000 up to 1000000
+     * @param newFee Transfer fee expressed in units of the new precision
+     * @param feeEventsEnabled Whether FeeUpdated is emitted
+     *
+     * With a change delay set, newFee must restate the live rate exactly, so the
+     * delay cannot be bypassed. Rejected while a fee change is scheduled, as it is
+     * expressed at the old precision.
+     */
+    function updateFeePrecision(
+        FeeSchedule storage self,
+        uint256 newPrecision,
+        uint256 newFee,
+        bool feeEventsEnabled
+    ) external {
+        if (
+            newPrecision < FEE_DENOMINATOR ||
+            newPrecision > MAX_FEE_PRECISION ||
+            newPrecision % FEE_DENOMINATOR != 0
+        ) revert IBridgeAdmin.InvalidFeePrecision();
+        if (newFee > _maxTransferFee(newPrecision)) revert IBridgeAdmin.FeeTooHigh();
+        if (newFee < _minTransferFee(self, newPrecision)) revert IBridgeAdmin.FeeTooLow();
+        applyPendingTransferFee(self, feeEventsEnabled);
+        if (self.pendingEffectiveAt != 0) revert IBridgeAdmin.FeeChangePending();
+        if (self.changeDelay != 0 && newFee * self.precision != self.transferFee * newPrecision) {
+            revert IBridgeAdmin.FeeChangeMustBeScheduled();
+        }
+        self.precision = newPrecision;
+        self.transferFee = newFee;
+        emit IBridgeAdmin.FeePrecisionUpdated(newPrecision);
+        if (feeEventsEnabled) {
+            emit IBridgeAdmin.FeeUpdated(newFee, self.operationFee);
+        }
+    }
+
+    /**
+     * @dev Updates the flat operation fee
+     * @param self Fee schedule
+     * @param newFee New fee amount, within maxTotalFee
+     * @param feeEventsEnabled Whether FeeUpdated is emitted
+     */
+    function updateOperationFee(FeeSchedule storage self, uint256 newFee, bool feeEventsEnabled) external {
+        if (newFee > MAX_OPERATION_FEE) revert IBridgeAdmin.FeeTooHigh();
+        if (self.maxTotalFee != 0 && newFee > self.maxTotalFee) revert IBridgeAdmin.FeeAboveTotalCap();
+        applyPendingTransferFee(self, feeEventsEnabled);
+        self.operationFee = newFee;
+        if (feeEventsEnabled) {
+            emit IBridgeAdmin.FeeUpdated(self.transferFee, newFee);
+        }
+    }
+
+    /**
+     * @dev Updates the absolute cap on the combined fee charged per bridge
+     * @param self Fee schedule
+     * @param newMaxTotalFee New cap in tokens (0 disables), not below the operation fee
+     */
+    function updateMaxTotalFee(FeeSchedule storage self, uint256 newMaxTotalFee) external {
+        if (newMaxTotalFee != 0 && newMaxTotalFee < self.operationFee) revert IBridgeAdmin.CapBelowOperationFee();
+        self.maxTotalFee = newMaxTotalFee;
+        emit IBridgeAdmin.MaxTotalFeeUpdated(newMaxTotalFee);
+    }
+
+    /**
+     * @dev Updates the lowest transfer fee that may be set
+     * @param self Fee schedule
+     * @param newMinFeeBasisPoints New floor, not above the current or a scheduled fee
+     * @param feeEventsEnabled Whether FeeUpdated is emitted for a matured scheduled fee
+     */
+    function updateMinFeeBasisPoints(
+        FeeSchedule storage self,
+        uint256 newMinFeeBasisPoints,
+        bool feeEventsEnabled
+    ) external {
+        if (newMinFeeBasisPoints > MAX_TRANSFER_FEE) revert IBridgeAdmin.FeeTooHigh();
+        applyPendingTransferFee(self, feeEventsEnabled);
+        self.minFeeBasisPoints = newMinFeeBasisPoints;
+        uint256 minFee = _minTransferFee(self, self.precision);
+        if (self.transferFee < minFee) revert IBridgeAdmin.FeeTooLow();
+        if (self.pendingEffectiveAt != 0 && self.pendingTransferFee < minFee) {
+            revert IBridgeAdmin.ScheduledFeeTooLow();
+        }
+        emit IBridgeAdmin.MinFeeBasisPointsUpdated(newMinFeeBasisPoints);
+    }
+
+    /**
+     * @dev Returns the transfer fee charged right now, including a matured scheduled change
+     */
+    function currentTransferFee(FeeSchedule storage self) internal view returns (uint256) {
+        if (self.pendingEffectiveAt != 0 && block.timestamp >= self.pendingEffectiveAt) {
+            return self.pendingTransferFee;
+        }
+        return self.transferFee;
+    }
+
+    /**
+     * @dev Commits a scheduled transfer fee once its delay has elapsed
+     */
+    function applyPendingTransferFee(FeeSchedule storage self, bool feeEventsEnabled) internal {
+        if (self.pendingEffectiveAt != 0 && block.timestamp >= self.pendingEffectiveAt) {
+            self.transferFee = self.pendingTransferFee;
+            self.pendingTransferFee = 0;
+            self.pendingEffectiveAt = 0;
+            if (feeEventsEnabled) {
+                emit IBridgeAdmin.FeeUpdated(self.transferFee, self.operationFee);
+            }
+        }
+    }
+
+    /**
+     * @dev Trips the kill switch, clearing any proposed reset
+     */
+    function trip(KillSwitch storage self) external {
+        if (self.tripped) revert IBridgeAdmin.KillSwitchActive();
+        self.tripped = true;
+        self.resetReadyAt = 0;
+        emit IBridgeAdmin.KillSwitchTripped();
+    }
+
+    /**
+     * @dev Starts the delay after which the kill switch may be reset
+     */
+    function proposeReset(KillSwitch storage self) external {
+        if (!self.tripped) revert IBridgeAdmin.KillSwitchNotActive();
+        self.resetReadyAt = block.timestamp + self.resetDelay;
+        emit IBridgeAdmin.KillSwitchResetProposed(self.resetReadyAt);
+    }
+
+    /**
+     * @dev Resets the kill switch once a proposal is at least resetDelay old
+     */
+    function reset(KillSwitch storage self) external {
+        if (!self.tripped) revert IBridgeAdmin.KillSwitchNotActive();
+        if (self.resetReadyAt == 0) revert IBridgeAdmin.ResetNotProposed();
+        if (block.timestamp < self.resetReadyAt) revert IBridgeAdmin.ResetDelayNotElapsed();
+        self.tripped = false;
+        self.resetReadyAt = 0;
+        emit IBridgeAdmin.KillSwitchReset();
+    }
+
+    /**
+     * @dev Updates the reset delay, only while the kill switch is not active
+     * @param self Kill switch
+     * @param newResetDelay New delay in seconds, at most MAX_KILL_SWITCH_RESET_DELAY
+     */
+    function updateResetDelay(KillSwitch storage self, uint256 newResetDelay) external {
+        if (self.tripped) revert IBridgeAdmin.KillSwitchActive();
+        if (newResetDelay > MAX_KILL_SWITCH_RESET_DELAY) revert IBridgeAdmin.DelayTooLong();
+        self.resetDelay = newResetDelay;
+        emit IBridgeAdmin.KillSwitchResetDelayUpdated(newResetDelay);
+    }
+
+    /**
+     * @dev Updates the maximum number of chains that can hold a configuration
+     * @param self Chain registry
+     * @param newMaxConfigs New cap (0 disables)
+     */
+    function updateMaxConfigs(ChainRegistry storage self, uint256 newMaxConfigs) external {
+        self.maxConfigs = newMaxConfigs;
+        emit IBridgeAdmin.MaxChainConfigsUpdated(newMaxConfigs);
+    }
+
+    /**
+     * @dev Removes a chain's configuration, freeing a slot under maxConfigs
+     * @param self Chain registry
+     * @param chain Destination chain identifier
+     *
+     * Chain fees must be withdrawn and a paused chain resumed first.
+     * Does not remove the chain from the allowlist.
+     */
+    function clearConfig(ChainRegistry storage self, string calldata chain) external {
+        bytes32 chainKey = keccak256(bytes(chain));
+        ChainConfig storage config = self.configs[chainKey];
+        if (!config.registered) revert IBridgeAdmin.ChainNotConfigured();
+        if (config.paused) revert IBridgeAdmin.ChainPaused();
+        if (self.collectedFees[chainKey] != 0) revert IBridgeAdmin.ChainFeesPending();
+        self.digest ^= _entryHash(self, chainKey);
+        delete self.configs[chainKey];
+        self.count -= 1;
+        emit IBridgeAdmin.ChainConfigCleared(chain);
+    }
+
+    /**
+     * @dev Sets the token decimals used on a destination chain
+     */
+    function setRemoteDecimals(ChainRegistry storage self, string calldata chain, uint8 remoteDecimals) external {
+        if (remoteDecimals > MAX_TOKEN_DECIMALS) revert IBridgeAdmin.UnsupportedTokenDecimals();
+        ChainConfig storage config = _configForUpdate(self, chain);
+        config.remoteDecimals = remoteDecimals;
+        config.hasRemoteDecimals = true;
+        _commit(self, chain);
+        emit IBridgeAdmin.ChainRemoteDecimalsUpdated(chain, remoteDecimals);
+    }
+
+    /**
+     * @dev Sets the expected relay time for a destination chain
+     */
+    function setRelayTimeout(ChainRegistry storage self, string calldata chain, uint64 expectedRelaySeconds) external {
+        _configForUpdate(self, chain).expectedRelaySeconds = expectedRelaySeconds;
+        _commit(self, chain);
+        emit IBridgeAdmin.ChainRelayTimeoutUpdated(chain, expectedRelaySeconds);
+    }
+
+    /**
+     * @dev Pauses or resumes a destination chain without registering it,
+     * so an incident pause is never blocked by maxConfigs
+     */
+    function setPaused(ChainRegistry storage self, string calldata chain, bool chainPaused) external {
+        if (bytes(chain).length == 0) revert IBridgeAdmin.InvalidChain();
+        bytes32 chainKey = keccak256(bytes(chain));
+        self.digest ^= _entryHash(self, chainKey);
+        self.configs[chainKey].paused = chainPaused;
+        self.digest ^= _entryHash(self, chainKey);
+        emit IBridgeAdmin.ChainPausedUpdated(chain, chainPaused);
+    }
+
+    /**
+     * @dev Sets the smallest unit a destination chain can mint
+     */
+    function setGranularity(ChainRegistry storage self, string calldata chain, uint256 remoteGranularity) external {
+        _configForUpdate(self, chain).remoteGranularity = remoteGranularity;
+        _commit(self, chain);
+        emit IBridgeAdmin.ChainGranularityUpdated(chain, remoteGranularity);
+    }
+
+    /**
+     * @dev Sets the minimum amount delivered for bridges to a destination chain
+     */
+    function setMinAmount(ChainRegistry storage self, string calldata chain, uint256 minAmount) external {
+        _configForUpdate(self, chain).minAmount = minAmount;
+        _commit(self, chain);
+        emit IBridgeAdmin.ChainMinAmountUpdated(chain, minAmount);
+    }
+
+    /**
+     * @dev Sets the recipient of fees from bridges to a destination chain
+     *
+     * The caller checks the recipient against the treasury allowlist.
+     */
+    function setFeeRecipient(ChainRegistry storage self, string calldata chain, address recipient) external {
+        _requireFeesWithdrawnIfCleared(self, chain, recipient);
+        _configForUpdate(self, chain).feeRecipient = recipient;
+        _commit(self, chain);
+        emit IBridgeAdmin.ChainFeeRecipientUpdated(chain, recipient);
+    }
+
+    /**
+     * @dev Configures several destination chains, reverting the whole batch on any invalid entry
+     *
+     * The caller checks the fee recipients against the treasury allowlist.
+     */
+    function configure(ChainRegistry storage self, ChainSetup[] calldata entries) external {
+        if (entries.length == 0 || entries.length > MAX_CHAIN_BATCH) revert IBridgeAdmin.InvalidBatchSize();
+        for (uint256 i = 0; i < entries.length; i++) {
+            ChainSetup calldata entry = entries[i];
+            _requireFeesWithdrawnIfCleared(self, entry.chain, entry.feeRecipient);
+            ChainConfig storage config = _configForUpdate(self, entry.chain);
+            config.feeRecipient = entry.feeRecipient;
+            config.expectedRelaySeconds = entry.expectedRelaySeconds;
+            config.remoteGranularity = entry.remoteGranularity;
+            config.minAmount = entry.minAmount;
+            if (entry.hasRemoteDecimals) {
+                if (entry.remoteDecimals > MAX_TOKEN_DECIMALS) revert IBridgeAdmin.UnsupportedTokenDecimals();
+                config.remoteDecimals = entry.remoteDecimals;
+                config.hasRemoteDecimals = true;
+                emit IBridgeAdmin.ChainRemoteDecimalsUpdated(entry.chain, entry.remoteDecimals);
+            }
+            _commit(self, entry.chain);
+            emit IBridgeAdmin.ChainFeeRecipientUpdated(entry.chain, entry.feeRecipient);
+            emit IBridgeAdmin.ChainRelayTimeoutUpdated(entry.chain, entry.expectedRelaySeconds);
+            emit IBridgeAdmin.ChainGranularityUpdated(entry.chain, entry.remoteGranularity);
+            emit IBridgeAdmin.ChainMinAmountUpdated(entry.chain, entry.minAmount);
+        }
+    }
+
+    /**
+     * @dev Adds or removes a destination chain from the allowlist
+     */
+    function setAllowed(ChainRegistry storage self, string calldata chain, bool allowed) external {
+        if (bytes(chain).length == 0) revert IBridgeAdmin.InvalidChain();
+        bytes32 chainKey = keccak256(bytes(chain));
+        if (self.allowed[chainKey] != allowed) {
+            self.digest ^= keccak256(abi.encode("allowedChain", chainKey));
+        }
+        self.allowed[chainKey] = allowed;
+        emit IBridgeAdmin.ChainAllowlistUpdated(chain, allowed);
+    }
+
+    /**
+     * @dev Enables or disables the destination chain allowlist
+     */
+    function setAllowlistEnabled(ChainRegistry storage self, bool enabled) external {
+        self.allowlistEnabled = enabled;
+        emit IBridgeAdmin.ChainAllowlistToggled(enabled);
+    }
+
+    /**
+     * @dev Returns the maximum transfer fee at a given precision
+     */
+    function _maxTransferFee(uint256 precision) private pure returns (uint256) {
+        return (MAX_TRANSFER_FEE * precision) / FEE_DENOMINATOR;
+    }
+
+    /**
+     * @dev Returns minFeeBasisPoints restated in units of the given precision
+     */
+    function _minTransferFee(FeeSchedule storage self, uint256 precision) private view returns (uint256) {
+        return (self.minFeeBasisPoints * precision) / FEE_DENOMINATOR;
+    }
+
+    /**
+     * @dev Rejects clearing the fee recipient of a chain that still holds unwithdrawn fees
+     */
+    function _requireFeesWithdrawnIfCleared(
+        ChainRegistry storage self,
+        string calldata chain,
+        address recipient
+    ) private view {
+        if (recipient == address(0) && self.collectedFees[keccak256(bytes(chain))] != 0) {
+            revert IBridgeAdmin.ChainFeesNotWithdrawn();
+        }
+    }
+
+    /**
+     * @dev Returns a chain's configuration for modification, registering it on first use
+     *
+     * Callers must call _commit once the modification is done.
+     */
+    function _configForUpdate(
+        ChainRegistry storage self,
+        string calldata chain
+    ) private returns (ChainConfig storage config) {
+        if (bytes(chain).length == 0) revert IBridgeAdmin.InvalidChain();
+        bytes32 chainKey = keccak256(bytes(chain));
+        self.digest ^= _entryHash(self, chainKey);
+        config = self.configs[chainKey];
+        if (!config.registered) {
+            if (self.maxConfigs != 0 && self.count >= self.maxConfigs) revert IBridgeAdmin.TooManyChainConfigs();
+            config.registered = true;
+            self.count += 1;
+        }
+    }
+
+    /**
+     * @dev Folds a chain's updated configuration back into the digest
+     */
+    function _commit(ChainRegistry storage self, string calldata chain) private {
+        self.digest ^= _entryHash(self, keccak256(bytes(chain)));
+    }
+
+    /**
+     * @dev Hashes a chain's configuration, zero for a chain with nothing set
+     */
+    function _entryHash(ChainRegistry storage self, bytes32 chainKey) private view returns (bytes32) {
+        ChainConfig memory config = self.configs[chainKey];
+        if (!config.registered && !config.paused) {
+            return bytes32(0);
+        }
+        return keccak256(abi.encode(chainKey, config));
+    }
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: GPL-3.0
+pragma solidity >=0.8.0 <0.9.0;
+
+import {Bridge} from "./Bridge.sol";
+import {IERC20} from "@openzeppelin/contracts/token/ERC20/IERC20.sol";
+
+/**
+ * @title MockBridgeCaller
+ * @dev Mock contract composing receiveAsset for testing purposes
+ */
+contract MockBridgeCaller {
+    Bridge public immutable bridge;
+    uint256 public lastBridgeId;
+    uint256 public lastFee;
+    uint256 public lastAmountAfterFee;
+    uint256 public lastBlockNumber;
+
+    constructor(Bridge bridge_) {
+        bridge = bridge_;
+    }
+
+    function bridgeTokens(uint256 amount, string calldata destinationChain, address destinationAddress) external {
+        IERC20(bridge.tokenAddress()).approve(address(bridge), amount);
+        Bridge.ReceiveResult memory result = bridge.receiveAsset(amount, destinationChain, destinationAddress);
+        lastBridgeId = result.bridgeId;
+        lastFee = result.fee;
+        lastAmountAfterFee = result.amountAfterFee;
+        lastBlockNumber = result.blockNumber;
+    }
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: GPL-3.0
+pragma solidity >=0.8.0 <0.9.0;
+
+import "@openzeppelin/contracts/token/ERC20/ERC20.sol";
+
+/**
+ * @title MockFeeOnTransferToken
+ * @dev Mock token that burns a percentage of every transfer, for testing purposes
+ */
+contract MockFeeOnTransferToken is ERC20 {
+    uint256 public transferFeeBps;
+
+    constructor(
+        string memory name,
+        string memory symbol,
+        uint256 transferFeeBps_
+    ) ERC20(name, symbol) {
+        transferFeeBps = transferFeeBps_;
+    }
+
+    function mint(address to, uint256 amount) external {
+        _mint(to, amount);
+    }
+
+    function burnFrom(address account, uint256 amount) external {
+        _burn(account, amount);
+    }
+
+    function _transfer(address from, address to, uint256 amount) internal override {
+        uint256 fee = (amount * transferFeeBps) / 10000;
+        super._transfer(from, to, amount - fee);
+        if (fee != 0) {
+            _burn(from, fee);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: GPL-3.0
+pragma solidity >=0.8.0 <0.9.0;
+
+import {IBridgeMintHook} from "./interfaces/IBridgeMintHook.sol";
+
+/**
+ * @title MockMintHook
+ * @dev Mock post-mint hook for testing purposes
+ */
+contract MockMintHook is IBridgeMintHook {
+    uint256 public calls;
+    address public lastRecipient;
+    uint256 public lastAmount;
+    bool public shouldRevert;
+
+    function setShouldRevert(bool shouldRevert_) external {
+        shouldRevert = shouldRevert_;
+    }
+
+    function onBridgeMint(address recipient, uint256 amount) external override {
+        require(!shouldRevert, "Hook failed");
+        calls += 1;
+        lastRecipient = recipient;
+        lastAmount = amount;
+    }
+}
@@ -2,6 +2,7 @@
 pragma solidity >=0.8.0 <0.9.0;
 
 import {Bridge} from "./Bridge.sol";
+import {BridgeAdminLib} from "./BridgeAdminLib.sol";
 import {Ownable} from "@openzeppelin/contracts/access/Ownable.sol";
 import {Ownable2Step} from "@openzeppelin/contracts/access/Ownable2Step.sol";
 
@@ -67,12 +68,15 @@ contract Oracle is Ownable2Step {
      * Security:
      * - Only callable by owner
      * - Validates bridge initialization
-     * - Emits event for tracking
+     * - Emits event for tracking when the change applies immediately
      */
     function updateTransferFee(uint256 fee) external onlyOwner {
         require(bridge != address(0), "Bridge not initialized");
         Bridge(bridge).updateTransferFee(fee);
-        emit FeeUpdated(fee, Bridge(bridge).operationFee());
+        // A delayed change is only scheduled; the bridge emits TransferFeeScheduled
+        if (Bridge(bridge).feeChangeDelay() == 0 && _feeEventsEnabled()) {
+            emit FeeUpdated(fee, Bridge(bridge).operationFee());
+        }
     }
 
     /**
@@ -87,7 +91,251 @@ contract Oracle is Ownable2Step {
     function updateOperationFee(uint256 fee) external onlyOwner {
         require(bridge != address(0), "Bridge not initialized");
         Bridge(bridge).updateOperationFee(fee);
-        emit FeeUpdated(Bridge(bridge).transferFee(), fee);
+        if (_feeEventsEnabled()) {
+            emit FeeUpdated(Bridge(bridge).currentTransferFee(), fee);
+        }
+    }
+
+    /**
+     * @dev Updates the absolute cap on the combined bridge fee
+     * @param cap New cap in tokens (0 disables)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateMaxTotalFee(uint256 cap) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateMaxTotalFee(cap);
+    }
+
+    /**
+     * @dev Updates the governance floor on the bridge transfer fee
+     * @param floor New minimum in basis points
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateMinFeeBasisPoints(uint256 floor) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateMinFeeBasisPoints(floor);
+    }
+
+    /**
+     * @dev Updates the delay before transfer fee changes take effect on the bridge
+     * @param delay New delay in seconds (0 applies changes immediately)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateFeeChangeDelay(uint256 delay) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateFeeChangeDelay(delay);
+    }
+
+    /**
+     * @dev Updates the transfer fee precision on the bridge
+     * @param precision New fee divisor
+     * @param fee Transfer fee in units of the new precision
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     * - Emits event for tracking
+     */
+    function updateFeePrecision(uint256 precision, uint256 fee) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateFeePrecision(precision, fee);
+        if (_feeEventsEnabled()) {
+            emit FeeUpdated(fee, Bridge(bridge).operationFee());
+        }
+    }
+
+    /**
+     * @dev Updates the minimum amount after fees on the bridge
+     * @param amount New minimum amount after fees (0 disables)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateMinBridgedAmount(uint256 amount) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateMinBridgedAmount(amount);
+    }
+
+    /**
+     * @dev Updates the native balance users need to bridge
+     * @param amount New minimum in wei (0 disables)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateMinUserNativeBalance(uint256 amount) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateMinUserNativeBalance(amount);
+    }
+
+    /**
+     * @dev Sets the post-mint hook on the bridge
+     * @param hook Hook contract address (zero disables)
+     * @param revertOnFailure Whether a failing hook reverts the mint
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setPostMintHook(address hook, bool revertOnFailure) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setPostMintHook(hook, revertOnFailure);
+    }
+
+    /**
+     * @dev Enables or disables verbose accounting events on the bridge
+     * @param enabled Whether bridges emit extra reconciliation events
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setVerboseEvents(bool enabled) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setVerboseEvents(enabled);
+    }
+
+    /**
+     * @dev Sets the name the bridge reports for this chain in route events
+     * @param name Chain identifier
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setLocalChainName(string calldata name) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setLocalChainName(name);
+    }
+
+    /**
+     * @dev Mutes selected optional events on the bridge
+     * @param mask Bitmask of Bridge EVENT_* constants to suppress; EVENT_FEE_UPDATED also mutes this contract's FeeUpdated
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setEventMask(uint16 mask) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setEventMask(mask);
+    }
+
+    /**
+     * @dev Enables or disables proof-of-burn receipts on the bridge
+     * @param enabled Whether bridges also emit a BurnReceipt event
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setBurnReceiptsEnabled(bool enabled) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setBurnReceiptsEnabled(enabled);
+    }
+
+    /**
+     * @dev Updates the confirmation depth advertised in burn receipts
+     * @param newMinConfirmations Number of blocks relayers should wait
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateMinConfirmations(uint256 newMinConfirmations) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateMinConfirmations(newMinConfirmations);
+    }
+
+    /**
+     * @dev Enables or disables generic cross-chain messages on the bridge
+     * @param enabled Whether bridges also publish a MessagePublished event
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setGenericMessagesEnabled(bool enabled) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setGenericMessagesEnabled(enabled);
+    }
+
+    /**
+     * @dev Updates the per-user daily outbound cap on the bridge
+     * @param cap New per-user daily cap (0 disables)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateMaxDailyPerUser(uint256 cap) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateMaxDailyPerUser(cap);
+    }
+
+    /**
+     * @dev Updates the total supply cap enforced by the bridge on mints
+     * @param cap New supply cap (0 disables)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateMaxTotalSupply(uint256 cap) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateMaxTotalSupply(cap);
+    }
+
+    /**
+     * @dev Updates the per-recipient lifetime mint cap on the bridge
+     * @param cap New per-recipient cap (0 disables)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateMaxLifetimePerRecipient(uint256 cap) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateMaxLifetimePerRecipient(cap);
+    }
+
+    /**
+     * @dev Updates the per-recipient windowed mint cap on the bridge
+     * @param cap New per-recipient cap per window (0 disables)
+     * @param window Length of the window in seconds
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateRecipientWindowCap(uint256 cap, uint256 window) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateRecipientWindowCap(cap, window);
+    }
+
+    /**
+     * @dev Grants or revokes fee exemption for a user on the bridge
+     * @param user Address of the user
+     * @param exempt Whether the user bridges without fees
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setFeeExempt(address user, bool exempt) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setFeeExempt(user, exempt);
     }
 
     /**
@@ -104,6 +352,20 @@ contract Oracle is Ownable2Step {
         emit BridgeStateChanged(true);
     }
 
+    /**
+     * @dev Pauses the bridge and has it emit a config and balance snapshot
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     * - Emits event for tracking
+     */
+    function emergencyBridgeSnapshot() external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).emergencySnapshot();
+        emit BridgeStateChanged(true);
+    }
+
     /**
      * @dev Unpauses bridge operations
      *
@@ -118,6 +380,150 @@ contract Oracle is Ownable2Step {
         emit BridgeStateChanged(false);
     }
 
+    /**
+     * @dev Trips the bridge kill switch, blocking all token movement
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function tripBridgeKillSwitch() external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).tripKillSwitch();
+    }
+
+    /**
+     * @dev Resets the bridge kill switch, re-enabling token movement
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function resetBridgeKillSwitch() external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).resetKillSwitch();
+    }
+
+    /**
+     * @dev Proposes a bridge kill switch reset, starting its delay
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function proposeBridgeKillSwitchReset() external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).proposeKillSwitchReset();
+    }
+
+    /**
+     * @dev Updates the delay before a proposed kill switch reset can be applied
+     * @param delay New delay in seconds (0 still requires a proposal)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateKillSwitchResetDelay(uint256 delay) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateKillSwitchResetDelay(delay);
+    }
+
+    /**
+     * @dev Updates the minimum time in pause before sunset actions on the bridge
+     * @param duration New cooling-off period in seconds (0 disables)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateMinPauseDuration(uint256 duration) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateMinPauseDuration(duration);
+    }
+
+    /**
+     * @dev Updates the minimum interval between fee withdrawals on the bridge
+     * @param cooldown New interval in seconds (0 disables)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateWithdrawCooldown(uint256 cooldown) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateWithdrawCooldown(cooldown);
+    }
+
+    /**
+     * @dev Approves or revokes a fee withdrawal destination on the bridge
+     * @param treasury Address of the treasury
+     * @param approved Whether fees may be withdrawn to it
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setApprovedTreasury(address treasury, bool approved) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setApprovedTreasury(treasury, approved);
+    }
+
+    /**
+     * @dev Enables or disables the bridge's treasury allowlist
+     * @param enabled Whether fees may only go to approved treasuries
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setTreasuryAllowlistEnabled(bool enabled) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setTreasuryAllowlistEnabled(enabled);
+    }
+
+    /**
+     * @dev Adds or removes a destination chain from the bridge's chain allowlist
+     * @param chain Exact destination chain identifier
+     * @param allowed Whether bridges to the chain are accepted while the allowlist is enabled
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setChainAllowed(string memory chain, bool allowed) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setChainAllowed(chain, allowed);
+    }
+
+    /**
+     * @dev Enables or disables the bridge's destination chain allowlist
+     * @param enabled Whether bridges are only accepted to allowed chains
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setChainAllowlistEnabled(bool enabled) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setChainAllowlistEnabled(enabled);
+    }
+
+    /**
+     * @dev Permanently disables minting through the bridge on sunset
+     *
+     * The token owner keeps its own mint and burnFrom authority on TokenManager.
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     * - Bridge must be paused
+     */
+    function relinquishBridgeMinting() external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).relinquishMinting();
+    }
+
     /**
      * @dev Withdraws accumulated fees from the bridge
      * @param to Address to receive the fees
@@ -132,6 +538,182 @@ contract Oracle is Ownable2Step {
         Bridge(bridge).withdrawFees(to);
     }
 
+    /**
+     * @dev Updates the maximum number of chain configurations on the bridge
+     * @param cap New cap (0 disables)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function updateMaxChainConfigs(uint256 cap) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).updateMaxChainConfigs(cap);
+    }
+
+    /**
+     * @dev Removes a chain's configuration from the bridge
+     * @param chain Destination chain identifier
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function clearChainConfig(string calldata chain) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).clearChainConfig(chain);
+    }
+
+    /**
+     * @dev Sets the destination chain's token decimals on the bridge
+     * @param chain Destination chain identifier
+     * @param decimals Token decimals on that chain
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setChainRemoteDecimals(string calldata chain, uint8 decimals) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setChainRemoteDecimals(chain, decimals);
+    }
+
+    /**
+     * @dev Sets the expected relay time for a destination chain on the bridge
+     * @param chain Destination chain identifier
+     * @param expectedRelaySeconds Expected relay time in seconds
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setChainRelayTimeout(string calldata chain, uint64 expectedRelaySeconds) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setChainRelayTimeout(chain, expectedRelaySeconds);
+    }
+
+    /**
+     * @dev Pauses or resumes bridging to one destination chain on the bridge
+     * @param chain Destination chain identifier
+     * @param chainPaused Whether bridges to that chain are blocked
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setChainPaused(string calldata chain, bool chainPaused) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setChainPaused(chain, chainPaused);
+    }
+
+    /**
+     * @dev Sets the minting granularity of a destination chain on the bridge
+     * @param chain Destination chain identifier
+     * @param remoteGranularity Required divisor of bridged amounts (0 disables)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setChainGranularity(string calldata chain, uint256 remoteGranularity) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setChainGranularity(chain, remoteGranularity);
+    }
+
+    /**
+     * @dev Sets the minimum bridged amount for a destination chain on the bridge
+     * @param chain Destination chain identifier
+     * @param minAmount Minimum amount after fees (0 disables)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setChainMinAmount(string calldata chain, uint256 minAmount) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setChainMinAmount(chain, minAmount);
+    }
+
+    /**
+     * @dev Configures automatic fee collection on the bridge
+     * @param threshold Fee balance that triggers a sweep (0 disables)
+     * @param recipient Address receiving swept fees
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setFeeSweep(uint256 threshold, address recipient) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setFeeSweep(threshold, recipient);
+    }
+
+    /**
+     * @dev Charges the bridge's flat fee in the native currency
+     * @param fee Wei per bridge (0 charges the token operation fee)
+     * @param collector Address receiving the native fees
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setNativeOperationFee(uint256 fee, address collector) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setNativeOperationFee(fee, collector);
+    }
+
+    /**
+     * @dev Withdraws the bridge's accrued native fees to its collector
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function withdrawNativeFees() external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).withdrawNativeFees();
+    }
+
+    /**
+     * @dev Withdraws a destination chain's accrued fees to its recipient
+     * @param chain Destination chain identifier
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function withdrawChainFees(string calldata chain) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).withdrawChainFees(chain);
+    }
+
+    /**
+     * @dev Sets the fee recipient for a destination chain on the bridge
+     * @param chain Destination chain identifier
+     * @param recipient Fee recipient (zero uses the shared fee pool)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function setChainFeeRecipient(string calldata chain, address recipient) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).setChainFeeRecipient(chain, recipient);
+    }
+
+    /**
+     * @dev Configures several destination chains on the bridge in one call
+     * @param entries Settings for each chain
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function configureChains(BridgeAdminLib.ChainSetup[] calldata entries) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).configureChains(entries);
+    }
+
     /**
      * @dev Updates the offchain processor address
      * @param newOffchain New offchain processor address
@@ -146,6 +728,31 @@ contract Oracle is Ownable2Step {
         Bridge(bridge).changeOffchain(newOffchain);
     }
 
+    /**
+     * @dev Sets the backup offchain processor address
+     * @param newBackup New backup processor address (zero removes it)
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function changeBackupProcessor(address newBackup) external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).changeBackupProcessor(newBackup);
+    }
+
+    /**
+     * @dev Promotes the backup offchain processor to primary
+     *
+     * Security:
+     * - Only callable by owner
+     * - Validates bridge initialization
+     */
+    function promoteBackupProcessor() external onlyOwner {
+        require(bridge != address(0), "Bridge not initialized");
+        Bridge(bridge).promoteBackupProcessor();
+    }
+
     /**
      * @dev Marks a transaction as processed
      * @param txHash Hash of the processed transaction
@@ -175,4 +782,12 @@ contract Oracle is Ownable2Step {
         validatedTransactions[txHash] = true;
         emit TransactionValidated(txHash);
     }
+
+    /**
+     * @dev Whether the bridge's event mask leaves fee update events on
+     */
+    function _feeEventsEnabled() internal view returns (bool) {
+        Bridge target = Bridge(bridge);
+        return target.eventMask() & target.EVENT_FEE_UPDATED() == 0;
+    }
 }
\ No newline at end of file
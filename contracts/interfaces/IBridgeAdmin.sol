@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: GPL-3.0
+pragma solidity >=0.8.4 <0.9.0;
+
+/**
+ * @title IBridgeAdmin
+ * @dev Events and errors of the Bridge's fee, kill switch and chain configuration admin
+ *
+ * Declared here so BridgeAdminLib can emit and raise them while they remain part
+ * of the Bridge ABI, which inherits this interface.
+ */
+interface IBridgeAdmin {
+    // Fee schedule
+    event FeeUpdated(
+        uint256 newTransferFee,
+        uint256 newOperationFee
+    );
+
+    event TransferFeeScheduled(
+        uint256 newTransferFee,
+        uint256 effectiveAt
+    );
+
+    event FeeChangeCancelled(
+        uint256 cancelledTransferFee,
+        uint256 effectiveAt
+    );
+
+    event FeeChangeDelayUpdated(
+        uint256 newFeeChangeDelay
+    );
+
+    event MaxTotalFeeUpdated(
+        uint256 newMaxTotalFee
+    );
+
+    event MinFeeBasisPointsUpdated(
+        uint256 newMinFeeBasisPoints
+    );
+
+    event FeePrecisionUpdated(
+        uint256 newFeePrecision
+    );
+
+    // Kill switch
+    event KillSwitchTripped();
+
+    event KillSwitchReset();
+
+    event KillSwitchResetProposed(
+        uint256 readyAt
+    );
+
+    event KillSwitchResetDelayUpdated(
+        uint256 newKillSwitchResetDelay
+    );
+
+    // Destination chains
+    event ChainAllowlistUpdated(
+        string chain,
+        bool allowed
+    );
+
+    event ChainAllowlistToggled(
+        bool enabled
+    );
+
+    event ChainFeeRecipientUpdated(
+        string chain,
+        address indexed recipient
+    );
+
+    event ChainRemoteDecimalsUpdated(
+        string chain,
+        uint8 remoteDecimals
+    );
+
+    event ChainRelayTimeoutUpdated(
+        string chain,
+        uint64 expectedRelaySeconds
+    );
+
+    event ChainPausedUpdated(
+        string chain,
+        bool paused
+    );
+
+    event ChainGranularityUpdated(
+        string chain,
+        uint256 remoteGranularity
+    );
+
+    event ChainMinAmountUpdated(
+        string chain,
+        uint256 minAmount
+    );
+
+    event ChainConfigCleared(
+        string chain
+    );
+
+    event MaxChainConfigsUpdated(
+        uint256 newMaxChainConfigs
+    );
+
+    error FeeTooHigh();
+    error FeeTooLow();
+    error TransferFeeTooHigh();
+    error OperationFeeTooHigh();
+    error InvalidFeePrecision();
+    error FeeChangePending();
+    error FeeChangeMustBeScheduled();
+    error FeeAboveTotalCap();
+    error CapBelowOperationFee();
+    error ScheduledFeeTooLow();
+    error DelayTooLong();
+    error KillSwitchActive();
+    error KillSwitchNotActive();
+    error ResetNotProposed();
+    error ResetDelayNotElapsed();
+    error InvalidChain();
+    error ChainNotConfigured();
+    error ChainPaused();
+    error ChainFeesPending();
+    error ChainFeesNotWithdrawn();
+    error TooManyChainConfigs();
+    error InvalidBatchSize();
+    error UnsupportedTokenDecimals();
+}
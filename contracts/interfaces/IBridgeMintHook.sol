@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: GPL-3.0
+pragma solidity >=0.8.0 <0.9.0;
+
+/**
+ * @title IBridgeMintHook
+ * @dev Interface for contracts notified by the Bridge after a successful mint
+ */
+interface IBridgeMintHook {
+    /**
+     * @dev Called by the bridge once tokens have been minted to the recipient
+     * @param recipient The address that received the minted tokens
+     * @param amount The amount of tokens minted
+     */
+    function onBridgeMint(address recipient, uint256 amount) external;
+}
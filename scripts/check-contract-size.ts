@@ -0,0 +1,28 @@
+import { artifacts } from "hardhat";
+
+// EIP-170 limit on deployed bytecode, not enforced by the hardhat network in this repo
+const MAX_CONTRACT_SIZE = 24576;
+const CONTRACTS = ["Bridge", "BridgeAdminLib", "Oracle", "TokenManager"];
+
+async function main() {
+  let oversized = false;
+
+  for (const name of CONTRACTS) {
+    const artifact = await artifacts.readArtifact(name);
+    const size = (artifact.deployedBytecode.length - 2) / 2;
+    const status = size > MAX_CONTRACT_SIZE ? "OVER LIMIT" : "ok";
+    console.log(`${name.padEnd(16)} ${size.toString().padStart(6)} / ${MAX_CONTRACT_SIZE} bytes  ${status}`);
+    oversized = oversized || size > MAX_CONTRACT_SIZE;
+  }
+
+  if (oversized) {
+    throw new Error("Deployed bytecode exceeds the EIP-170 limit");
+  }
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
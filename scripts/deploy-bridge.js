@@ -36,7 +36,12 @@ async function main() {
   
   // Deploy Bridge
   console.log("Deploying Bridge...");
-  const BridgeFactory = await ethers.getContractFactory("Bridge");
+  const BridgeAdminLibFactory = await ethers.getContractFactory("BridgeAdminLib");
+  const bridgeAdminLib = await BridgeAdminLibFactory.deploy();
+  await bridgeAdminLib.waitForDeployment();
+  const BridgeFactory = await ethers.getContractFactory("Bridge", {
+    libraries: { BridgeAdminLib: await bridgeAdminLib.getAddress() },
+  });
   const bridge = await BridgeFactory.deploy(
     tokenManagerAddress,
     transferFee,
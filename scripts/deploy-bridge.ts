@@ -31,7 +31,12 @@ async function main() {
 
   // Deploy Bridge manually
   console.log("Deploying Bridge...");
-  const BridgeFactory = await ethers.getContractFactory("Bridge");
+  const BridgeAdminLibFactory = await ethers.getContractFactory("BridgeAdminLib");
+  const bridgeAdminLib = await BridgeAdminLibFactory.deploy();
+  await bridgeAdminLib.waitForDeployment();
+  const BridgeFactory = await ethers.getContractFactory("Bridge", {
+    libraries: { BridgeAdminLib: await bridgeAdminLib.getAddress() },
+  });
   const transferFee = 100; // 1% (100 basis points)
   const operationFee = ethers.parseEther("1"); // 1 MRLN
   const bridge = await BridgeFactory.deploy(
@@ -7,7 +7,12 @@ async function main() {
     const tokenManagerAddress = "0x0B3547CD0E14e7D42f8921b0c370FdFD708bff6C"
     const oracleAddress = "0xe8F96D4daBdC090Df1fCDedB0c4182f19Aa16D12";
 
-    const BridgeFactory = await ethers.getContractFactory("Bridge");
+    const BridgeAdminLibFactory = await ethers.getContractFactory("BridgeAdminLib");
+    const bridgeAdminLib = await BridgeAdminLibFactory.deploy();
+    await bridgeAdminLib.waitForDeployment();
+    const BridgeFactory = await ethers.getContractFactory("Bridge", {
+      libraries: { BridgeAdminLib: await bridgeAdminLib.getAddress() },
+    });
     const transferFee = 5; // 1% (100 basis points)
     const operationFee = ethers.parseEther("1"); // 1 MRLN
 
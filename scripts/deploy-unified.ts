@@ -82,7 +82,12 @@ async function deployBridgeContracts(deployer: any, config: any, chainId: number
 
   // Deploy Bridge manually
   console.log("Deploying Bridge...");
-  const BridgeFactory = await ethers.getContractFactory("Bridge");
+  const BridgeAdminLibFactory = await ethers.getContractFactory("BridgeAdminLib");
+  const bridgeAdminLib = await BridgeAdminLibFactory.deploy();
+  await bridgeAdminLib.waitForDeployment();
+  const BridgeFactory = await ethers.getContractFactory("Bridge", {
+    libraries: { BridgeAdminLib: await bridgeAdminLib.getAddress() },
+  });
   const bridge = await BridgeFactory.deploy(
     tokenManagerAddress,
     params.transferFee,
@@ -158,7 +163,12 @@ async function deployDAOContracts(deployer: any, config: any, chainId: number, s
 
   // Deploy Bridge
   console.log("Deploying Bridge...");
-  const BridgeFactory = await ethers.getContractFactory("Bridge");
+  const BridgeAdminLibFactory = await ethers.getContractFactory("BridgeAdminLib");
+  const bridgeAdminLib = await BridgeAdminLibFactory.deploy();
+  await bridgeAdminLib.waitForDeployment();
+  const BridgeFactory = await ethers.getContractFactory("Bridge", {
+    libraries: { BridgeAdminLib: await bridgeAdminLib.getAddress() },
+  });
   const bridge = await BridgeFactory.deploy(
     tokenManagerAddress,
     params.transferFee,
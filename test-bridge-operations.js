@@ -21,8 +21,7 @@ async function main() {
     const TokenManager = await ethers.getContractFactory("TokenManager");
     const tokenManager = TokenManager.attach(DEPLOYED_ADDRESSES.TOKEN_MANAGER);
 
-    const Bridge = await ethers.getContractFactory("Bridge");
-    const bridge = Bridge.attach(DEPLOYED_ADDRESSES.BRIDGE);
+    const bridge = await ethers.getContractAt("Bridge", DEPLOYED_ADDRESSES.BRIDGE);
 
     const Oracle = await ethers.getContractFactory("Oracle");
     const oracle = Oracle.attach(DEPLOYED_ADDRESSES.ORACLE);
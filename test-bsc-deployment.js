@@ -43,8 +43,7 @@ async function main() {
         console.log("✅ Connected to TokenManager");
 
         // Connect to Bridge
-        const Bridge = await ethers.getContractFactory("Bridge");
-        const bridge = Bridge.attach(DEPLOYED_ADDRESSES.BRIDGE);
+        const bridge = await ethers.getContractAt("Bridge", DEPLOYED_ADDRESSES.BRIDGE);
         console.log("✅ Connected to Bridge");
 
         // Test 1: Check TokenManager basic info
@@ -1,6 +1,7 @@
 import { expect } from "chai";
-import { ethers } from "hardhat";
-import { Bridge, TokenManager, Oracle } from "../typechain-types";
+import { ethers, artifacts } from "hardhat";
+import { time } from "@nomicfoundation/hardhat-network-helpers";
+import { Bridge, TokenManager, Oracle, MockMintHook } from "../typechain-types";
 import { SignerWithAddress } from "@nomicfoundation/hardhat-ethers/signers";
 
 describe("Bridge", function () {
@@ -11,6 +12,7 @@ describe("Bridge", function () {
   let offchainProcessor: SignerWithAddress;
   let user1: SignerWithAddress;
   let user2: SignerWithAddress;
+  let bridgeLibraries: { BridgeAdminLib: string };
   
   const TRANSFER_FEE = 100n; // 1%
   const OPERATION_FEE = ethers.parseEther("1"); // 1 token
@@ -35,8 +37,14 @@ describe("Bridge", function () {
     );
     await tokenManager.waitForDeployment();
 
+    // Deploy the admin library the Bridge links against
+    const BridgeAdminLibFactory = await ethers.getContractFactory("BridgeAdminLib");
+    const bridgeAdminLib = await BridgeAdminLibFactory.deploy();
+    await bridgeAdminLib.waitForDeployment();
+    bridgeLibraries = { BridgeAdminLib: await bridgeAdminLib.getAddress() };
+
     // Deploy Bridge
-    const BridgeFactory = await ethers.getContractFactory("Bridge");
+    const BridgeFactory = await ethers.getContractFactory("Bridge", { libraries: bridgeLibraries });
     bridge = await BridgeFactory.deploy(
       await tokenManager.getAddress(),
       TRANSFER_FEE,
@@ -52,14 +60,6 @@ describe("Bridge", function () {
     // Set bridge and oracle in TokenManager
     await tokenManager.setBridgeAndOracle(await bridge.getAddress(), await oracle.getAddress());
 
-    // Complete ownership transfer for Bridge (required by Ownable2Step)
-    const oracleSigner = await ethers.getImpersonatedSigner(await oracle.getAddress());
-    await ethers.provider.send("hardhat_setBalance", [
-      oracleSigner.address,
-      "0x1000000000000000000"
-    ]);
-    await bridge.connect(oracleSigner).acceptOwnership();
-
     // Transfer some tokens to user1 for testing
     await tokenManager.transfer(user1.address, BRIDGE_AMOUNT);
   });
@@ -81,6 +81,19 @@ describe("Bridge", function () {
     it("Should set the correct offchain processor", async function () {
       expect(await bridge.offchainProcessor()).to.equal(offchainProcessor.address);
     });
+
+    it("Should reject an operation fee above the cap", async function () {
+      const BridgeFactory = await ethers.getContractFactory("Bridge", { libraries: bridgeLibraries });
+      await expect(
+        BridgeFactory.deploy(
+          await tokenManager.getAddress(),
+          TRANSFER_FEE,
+          ethers.parseEther("1000") + 1n,
+          await oracle.getAddress(),
+          offchainProcessor.address
+        )
+      ).to.be.revertedWithCustomError(bridge, "OperationFeeTooHigh");
+    });
   });
 
   describe("Bridge Operations", function () {
@@ -120,6 +133,13 @@ describe("Bridge", function () {
         .to.emit(bridge, "AssetMinted")
         .withArgs(user1.address, mintAmount);
     });
+
+    it("Should reject bridging to the zero address", async function () {
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", ethers.ZeroAddress))
+        .to.be.revertedWithCustomError(bridge, "InvalidDestinationAddress");
+      expect(await bridge.connect(user1).validateBridge.staticCall(ethers.parseEther("10"), "ETH", ethers.ZeroAddress))
+        .to.equal(await bridge.REJECT_INVALID_DESTINATION());
+    });
   });
 
   describe("Fee Management", function () {
@@ -156,4 +176,1745 @@ describe("Bridge", function () {
       expect(await tokenManager.balanceOf(user2.address)).to.equal(totalFee);
     });
   });
+
+  describe("Config Snapshot", function () {
+    it("Should emit the current config together with its hash", async function () {
+      const hash = await bridge.configHash();
+      await expect(bridge.dumpConfig())
+        .to.emit(bridge, "ConfigSnapshot")
+        .withArgs(
+          1n,
+          await tokenManager.getAddress(),
+          TRANSFER_FEE,
+          10000n,
+          OPERATION_FEE,
+          offchainProcessor.address,
+          ethers.ZeroAddress,
+          await oracle.getAddress(),
+          false,
+          hash
+        );
+    });
+
+    it("Should return the full config that the hash covers", async function () {
+      await oracle.updateMaxChainConfigs(10);
+      await oracle.setLocalChainName("ARB");
+      await oracle.changeBackupProcessor(owner.address);
+
+      const config = await bridge.getBridgeConfig();
+      expect(config.version).to.equal(1n);
+      expect(config.owner).to.equal(await oracle.getAddress());
+      expect(config.fees.transferFee).to.equal(TRANSFER_FEE);
+      expect(config.minting.backupProcessor).to.equal(owner.address);
+      expect(config.operations.maxChainConfigs).to.equal(10n);
+      expect(config.operations.localChainName).to.equal("ARB");
+
+      const configType = bridge.interface.getFunction("getBridgeConfig").outputs[0];
+      const encoded = ethers.AbiCoder.defaultAbiCoder().encode([configType], [config]);
+      expect(ethers.keccak256(encoded)).to.equal(await bridge.configHash());
+    });
+
+    it("Should change the hash when any field changes", async function () {
+      const hook = await (await ethers.getContractFactory("MockMintHook")).deploy();
+      const chainSetup = {
+        chain: "BSC", feeRecipient: user2.address, expectedRelaySeconds: 60, remoteGranularity: 0n,
+        minAmount: 0n, remoteDecimals: 18, hasRemoteDecimals: true
+      };
+      const setters: [string, () => Promise<unknown>][] = [
+        ["transferFee", () => oracle.updateTransferFee(200n)],
+        ["feePrecision", () => oracle.updateFeePrecision(1000000n, 20000n)],
+        ["operationFee", () => oracle.updateOperationFee(ethers.parseEther("2"))],
+        ["maxTotalFee", () => oracle.updateMaxTotalFee(ethers.parseEther("5"))],
+        ["minFeeBasisPoints", () => oracle.updateMinFeeBasisPoints(1n)],
+        ["feeChangeDelay", () => oracle.updateFeeChangeDelay(3600)],
+        ["pendingTransferFee", () => oracle.updateTransferFee(30000n)],
+        ["nativeOperationFee", () => oracle.setNativeOperationFee(ethers.parseEther("0.01"), owner.address)],
+        ["nativeFeeCollector", () => oracle.setNativeOperationFee(ethers.parseEther("0.01"), user2.address)],
+        ["feeSweepThreshold", () => oracle.setFeeSweep(ethers.parseEther("100"), owner.address)],
+        ["feeSweepRecipient", () => oracle.setFeeSweep(ethers.parseEther("100"), user2.address)],
+        ["approvedTreasuries", () => oracle.setApprovedTreasury(user2.address, true)],
+        ["treasuryAllowlistEnabled", () => oracle.setTreasuryAllowlistEnabled(true)],
+        ["allowedChains", () => oracle.setChainAllowed("ETH", true)],
+        ["chainAllowlistEnabled", () => oracle.setChainAllowlistEnabled(true)],
+        ["withdrawCooldown", () => oracle.updateWithdrawCooldown(60)],
+        ["feeExempt", () => oracle.setFeeExempt(user1.address, true)],
+        ["minBridgedAmount", () => oracle.updateMinBridgedAmount(ethers.parseEther("1"))],
+        ["minUserNativeBalance", () => oracle.updateMinUserNativeBalance(1n)],
+        ["maxDailyPerUser", () => oracle.updateMaxDailyPerUser(ethers.parseEther("1000"))],
+        ["maxChainConfigs", () => oracle.updateMaxChainConfigs(10)],
+        ["offchainProcessor", () => oracle.changeOffchainAddress(user2.address)],
+        ["backupProcessor", () => oracle.changeBackupProcessor(owner.address)],
+        ["promoteBackupProcessor", () => oracle.promoteBackupProcessor()],
+        ["maxTotalSupply", () => oracle.updateMaxTotalSupply(INITIAL_SUPPLY * 2n)],
+        ["maxLifetimePerRecipient", () => oracle.updateMaxLifetimePerRecipient(ethers.parseEther("1000"))],
+        ["maxPerRecipientWindow", () => oracle.updateRecipientWindowCap(ethers.parseEther("100"), 3600)],
+        ["recipientWindowDuration", () => oracle.updateRecipientWindowCap(ethers.parseEther("100"), 7200)],
+        ["postMintHook", async () => oracle.setPostMintHook(await hook.getAddress(), false)],
+        ["revertOnHookFailure", async () => oracle.setPostMintHook(await hook.getAddress(), true)],
+        ["verboseEvents", () => oracle.setVerboseEvents(true)],
+        ["burnReceiptsEnabled", () => oracle.setBurnReceiptsEnabled(true)],
+        ["genericMessagesEnabled", () => oracle.setGenericMessagesEnabled(true)],
+        ["minConfirmations", () => oracle.updateMinConfirmations(12)],
+        ["localChainName", () => oracle.setLocalChainName("ARB")],
+        ["eventMask", () => oracle.setEventMask(1)],
+        ["chain feeRecipient", () => oracle.setChainFeeRecipient("ETH", user2.address)],
+        ["chain remoteDecimals", () => oracle.setChainRemoteDecimals("ETH", 6)],
+        ["chain expectedRelaySeconds", () => oracle.setChainRelayTimeout("ETH", 900)],
+        ["chain remoteGranularity", () => oracle.setChainGranularity("ETH", 10n)],
+        ["chain minAmount", () => oracle.setChainMinAmount("ETH", 1n)],
+        ["configureChains", () => oracle.configureChains([chainSetup])],
+        ["clearChainConfig", () => oracle.clearChainConfig("BSC")],
+        ["chain paused", () => oracle.setChainPaused("SOL", true)],
+        ["minPauseDuration", () => oracle.updateMinPauseDuration(60)],
+        ["killSwitchResetDelay", () => oracle.updateKillSwitchResetDelay(2 * 24 * 60 * 60)],
+        ["paused", () => oracle.pauseBridge()],
+        ["mintingRelinquished", async () => {
+          await time.increase(60);
+          return oracle.relinquishBridgeMinting();
+        }],
+        ["killSwitchTripped", () => oracle.tripBridgeKillSwitch()],
+        ["killSwitchResetReadyAt", () => oracle.proposeBridgeKillSwitchReset()],
+      ];
+
+      const seen = new Set([await bridge.configHash()]);
+      for (const [field, setter] of setters) {
+        await setter();
+        const hash = await bridge.configHash();
+        expect(seen.has(hash), `hash unchanged after updating ${field}`).to.equal(false);
+        seen.add(hash);
+      }
+    });
+
+    it("Should hash chain configs independently of the order they were set", async function () {
+      await oracle.setChainRelayTimeout("ETH", 900);
+      await oracle.setChainRelayTimeout("BSC", 120);
+      const hash = await bridge.configHash();
+
+      await oracle.clearChainConfig("ETH");
+      await oracle.clearChainConfig("BSC");
+      await oracle.setChainRelayTimeout("BSC", 120);
+      await oracle.setChainRelayTimeout("ETH", 900);
+      expect(await bridge.configHash()).to.equal(hash);
+    });
+
+    it("Should reject a primary processor equal to the backup", async function () {
+      await oracle.changeBackupProcessor(owner.address);
+      await expect(oracle.changeOffchainAddress(owner.address))
+        .to.be.revertedWithCustomError(bridge, "BackupMatchesPrimary");
+    });
+  });
+
+  describe("Minimum Bridged Amount", function () {
+    beforeEach(async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await oracle.updateMinBridgedAmount(ethers.parseEther("1"));
+    });
+
+    it("Should reject a bridge where fees consume nearly the whole amount", async function () {
+      // 1.5 tokens - (1% + 1 token flat fee) leaves less than 1 token
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("1.5"), "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "AmountAfterFeeTooSmall");
+    });
+
+    it("Should allow a bridge that delivers at least the minimum", async function () {
+      const bridgeAmount = ethers.parseEther("10");
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "BridgeStarted");
+    });
+  });
+
+  describe("Fee Exemption", function () {
+    beforeEach(async function () {
+      await tokenManager.transfer(user2.address, BRIDGE_AMOUNT);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await tokenManager.connect(user2).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await oracle.setFeeExempt(user1.address, true);
+    });
+
+    it("Should let an exempt user bridge without fees", async function () {
+      const bridgeAmount = ethers.parseEther("10");
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "FeeExemptBridge")
+        .withArgs(user1.address, bridgeAmount)
+        .and.to.emit(bridge, "BridgeStarted")
+        .withArgs(user1.address, bridgeAmount, bridgeAmount, "ETH", user2.address);
+      expect(await tokenManager.balanceOf(await bridge.getAddress())).to.equal(0n);
+    });
+
+    it("Should charge a normal user the standard fee", async function () {
+      const bridgeAmount = ethers.parseEther("10");
+      const expectedFee = (bridgeAmount * TRANSFER_FEE) / 10000n + OPERATION_FEE;
+      const tx = await bridge.connect(user2).receiveAsset(bridgeAmount, "ETH", user1.address);
+      await expect(tx)
+        .to.emit(bridge, "BridgeStarted")
+        .withArgs(user2.address, bridgeAmount, bridgeAmount - expectedFee, "ETH", user1.address);
+      await expect(tx).not.to.emit(bridge, "FeeExemptBridge");
+      expect(await tokenManager.balanceOf(await bridge.getAddress())).to.equal(expectedFee);
+    });
+  });
+
+  describe("Token Decimals", function () {
+    it("Should store the token decimals", async function () {
+      expect(await bridge.tokenDecimals()).to.equal(18n);
+    });
+
+    it("Should accept a 12-decimal token", async function () {
+      const MockERC20Factory = await ethers.getContractFactory("MockERC20");
+      const token = await MockERC20Factory.deploy("Mock", "MCK", 12);
+      const BridgeFactory = await ethers.getContractFactory("Bridge", { libraries: bridgeLibraries });
+      const otherBridge = await BridgeFactory.deploy(
+        await token.getAddress(),
+        TRANSFER_FEE,
+        OPERATION_FEE,
+        await oracle.getAddress(),
+        offchainProcessor.address
+      );
+      expect(await otherBridge.tokenDecimals()).to.equal(12n);
+    });
+
+    it("Should reject a token with more than 18 decimals", async function () {
+      const MockERC20Factory = await ethers.getContractFactory("MockERC20");
+      const token = await MockERC20Factory.deploy("Mock", "MCK", 19);
+      const BridgeFactory = await ethers.getContractFactory("Bridge", { libraries: bridgeLibraries });
+      await expect(BridgeFactory.deploy(
+        await token.getAddress(),
+        TRANSFER_FEE,
+        OPERATION_FEE,
+        await oracle.getAddress(),
+        offchainProcessor.address
+      )).to.be.revertedWithCustomError(bridge, "UnsupportedTokenDecimals");
+    });
+  });
+
+  describe("Bridge Validation", function () {
+    it("Should emit a rejection event without reverting", async function () {
+      const bridgeAmount = ethers.parseEther("10");
+      // No allowance granted yet
+      expect(await bridge.connect(user1).validateBridge.staticCall(bridgeAmount, "ETH", user2.address))
+        .to.equal(await bridge.REJECT_INSUFFICIENT_ALLOWANCE());
+      await expect(bridge.connect(user1).validateBridge(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "BridgeRejected")
+        .withArgs(user1.address, await bridge.REJECT_INSUFFICIENT_ALLOWANCE());
+    });
+
+    it("Should report fees consuming the whole amount", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await expect(bridge.connect(user1).validateBridge(OPERATION_FEE, "ETH", user2.address))
+        .to.emit(bridge, "BridgeRejected")
+        .withArgs(user1.address, await bridge.REJECT_FEE_EXCEEDS_AMOUNT());
+    });
+
+    it("Should not emit a rejection for a valid bridge", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      const bridgeAmount = ethers.parseEther("10");
+      expect(await bridge.connect(user1).validateBridge.staticCall(bridgeAmount, "ETH", user2.address))
+        .to.equal(await bridge.REJECT_NONE());
+      await expect(bridge.connect(user1).validateBridge(bridgeAmount, "ETH", user2.address))
+        .not.to.emit(bridge, "BridgeRejected");
+    });
+  });
+
+  describe("Supply Cap", function () {
+    it("Should mint up to the cap and reject the overage", async function () {
+      const mintAmount = ethers.parseEther("10");
+      await oracle.updateMaxTotalSupply(INITIAL_SUPPLY + mintAmount);
+
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, mintAmount))
+        .to.emit(bridge, "AssetMinted")
+        .withArgs(user1.address, mintAmount);
+      expect(await tokenManager.totalSupply()).to.equal(INITIAL_SUPPLY + mintAmount);
+
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, 1n))
+        .to.be.revertedWithCustomError(bridge, "SupplyCapExceeded");
+    });
+
+    it("Should not limit mints when the cap is disabled", async function () {
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, INITIAL_SUPPLY))
+        .to.emit(bridge, "AssetMinted");
+    });
+  });
+
+  describe("Bridge Summary", function () {
+    it("Should return the current configuration", async function () {
+      const summary = await bridge.getBridgeSummary();
+      expect(summary.tokenAddress).to.equal(await tokenManager.getAddress());
+      expect(summary.tokenDecimals).to.equal(18n);
+      expect(summary.transferFee).to.equal(TRANSFER_FEE);
+      expect(summary.operationFee).to.equal(OPERATION_FEE);
+      expect(summary.offchainProcessor).to.equal(offchainProcessor.address);
+      expect(summary.paused).to.equal(false);
+    });
+
+    it("Should stay in sync after a fee update", async function () {
+      await oracle.updateTransferFee(250n);
+      await oracle.updateOperationFee(ethers.parseEther("3"));
+      const summary = await bridge.getBridgeSummary();
+      expect(summary.transferFee).to.equal(250n);
+      expect(summary.operationFee).to.equal(ethers.parseEther("3"));
+    });
+
+    it("Should report a native operation fee", async function () {
+      await oracle.setNativeOperationFee(ethers.parseEther("0.01"), user2.address);
+      const summary = await bridge.getBridgeSummary();
+      expect(summary.nativeOperationFee).to.equal(ethers.parseEther("0.01"));
+      expect(summary.nativeFeeCollector).to.equal(user2.address);
+    });
+
+    it("Should report the total fee cap", async function () {
+      await oracle.updateMaxTotalFee(ethers.parseEther("5"));
+      expect((await bridge.getBridgeSummary()).maxTotalFee).to.equal(ethers.parseEther("5"));
+    });
+  });
+
+  describe("Mint Recipient Validation", function () {
+    it("Should reject a mint targeting the bridge itself", async function () {
+      await expect(bridge.connect(offchainProcessor).mintAsset(await bridge.getAddress(), ethers.parseEther("10")))
+        .to.be.revertedWithCustomError(bridge, "MintToBridge");
+    });
+  });
+
+  describe("Per-User Daily Limit", function () {
+    const DAILY_CAP = ethers.parseEther("20");
+
+    beforeEach(async function () {
+      await tokenManager.transfer(user2.address, BRIDGE_AMOUNT);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await tokenManager.connect(user2).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await oracle.updateMaxDailyPerUser(DAILY_CAP);
+    });
+
+    it("Should exhaust one user's cap without affecting another", async function () {
+      await bridge.connect(user1).receiveAsset(ethers.parseEther("15"), "ETH", user2.address);
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "UserDailyLimitExceeded");
+
+      await expect(bridge.connect(user2).receiveAsset(ethers.parseEther("15"), "ETH", user1.address))
+        .to.emit(bridge, "BridgeStarted");
+    });
+
+    it("Should reset the window after a day", async function () {
+      await bridge.connect(user1).receiveAsset(DAILY_CAP, "ETH", user2.address);
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "UserDailyLimitExceeded");
+
+      await time.increase(24 * 60 * 60);
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.emit(bridge, "BridgeStarted");
+      expect(await bridge.userDailyVolume(user1.address)).to.equal(ethers.parseEther("10"));
+    });
+  });
+
+  describe("Backup Processor", function () {
+    beforeEach(async function () {
+      await oracle.changeBackupProcessor(user2.address);
+    });
+
+    it("Should let both the primary and backup processors mint", async function () {
+      const mintAmount = ethers.parseEther("10");
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, mintAmount))
+        .to.emit(bridge, "AssetMinted");
+      await expect(bridge.connect(user2).mintAsset(user1.address, mintAmount))
+        .to.emit(bridge, "AssetMinted");
+    });
+
+    it("Should reject mints from other accounts", async function () {
+      await expect(bridge.connect(user1).mintAsset(user1.address, ethers.parseEther("10")))
+        .to.be.revertedWithCustomError(bridge, "NotOffchainProcessor");
+    });
+
+    it("Should promote the backup to primary", async function () {
+      await expect(oracle.promoteBackupProcessor())
+        .to.emit(bridge, "OffchainProcessorChanged")
+        .withArgs(offchainProcessor.address, user2.address);
+      expect(await bridge.offchainProcessor()).to.equal(user2.address);
+      expect(await bridge.backupProcessor()).to.equal(ethers.ZeroAddress);
+
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("10")))
+        .to.be.revertedWithCustomError(bridge, "NotOffchainProcessor");
+    });
+  });
+
+  describe("Generic Messages", function () {
+    beforeEach(async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+    });
+
+    it("Should not publish messages by default", async function () {
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .not.to.emit(bridge, "MessagePublished");
+    });
+
+    it("Should publish a standardized message when enabled", async function () {
+      await oracle.setGenericMessagesEnabled(true);
+      const bridgeAmount = ethers.parseEther("10");
+      const amountAfterFee = bridgeAmount - (bridgeAmount * TRANSFER_FEE) / 10000n - OPERATION_FEE;
+      const payload = ethers.AbiCoder.defaultAbiCoder().encode(
+        ["address", "uint256", "string", "address"],
+        [user1.address, amountAfterFee, "ETH", user2.address]
+      );
+
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "MessagePublished")
+        .withArgs(await bridge.getAddress(), 1n, payload);
+      expect(await bridge.outboundNonce()).to.equal(1n);
+    });
+  });
+
+  describe("Post-Mint Hook", function () {
+    let hook: MockMintHook;
+
+    beforeEach(async function () {
+      const MockMintHookFactory = await ethers.getContractFactory("MockMintHook");
+      hook = await MockMintHookFactory.deploy();
+      await hook.waitForDeployment();
+    });
+
+    it("Should call the hook after a successful mint", async function () {
+      await oracle.setPostMintHook(await hook.getAddress(), true);
+      const mintAmount = ethers.parseEther("10");
+      await bridge.connect(offchainProcessor).mintAsset(user1.address, mintAmount);
+
+      expect(await hook.calls()).to.equal(1n);
+      expect(await hook.lastRecipient()).to.equal(user1.address);
+      expect(await hook.lastAmount()).to.equal(mintAmount);
+    });
+
+    it("Should revert the mint when the hook fails in strict mode", async function () {
+      await oracle.setPostMintHook(await hook.getAddress(), true);
+      await hook.setShouldRevert(true);
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("10")))
+        .to.be.revertedWith("Hook failed");
+    });
+
+    it("Should log a failing hook without reverting the mint", async function () {
+      await oracle.setPostMintHook(await hook.getAddress(), false);
+      await hook.setShouldRevert(true);
+      const mintAmount = ethers.parseEther("10");
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, mintAmount))
+        .to.emit(bridge, "PostMintHookFailed")
+        .withArgs(await hook.getAddress(), user1.address, mintAmount);
+      expect(await tokenManager.balanceOf(user1.address)).to.equal(BRIDGE_AMOUNT + mintAmount);
+    });
+
+    it("Should reject a hook without contract code", async function () {
+      await expect(oracle.setPostMintHook(user2.address, false)).to.be.revertedWithCustomError(bridge, "HookNotContract");
+      await oracle.setPostMintHook(ethers.ZeroAddress, false);
+      expect(await bridge.postMintHook()).to.equal(ethers.ZeroAddress);
+    });
+  });
+
+  describe("Fee Precision", function () {
+    const bridgeAmount = ethers.parseEther("10");
+
+    beforeEach(async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+    });
+
+    it("Should charge the same fee at standard and high precision for the same rate", async function () {
+      const standardFee = (bridgeAmount * TRANSFER_FEE) / 10000n + OPERATION_FEE;
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "BridgeStarted")
+        .withArgs(user1.address, bridgeAmount, bridgeAmount - standardFee, "ETH", user2.address);
+
+      // 1% expressed at 1,000,000 precision
+      await oracle.updateFeePrecision(1000000n, 10000n);
+      expect(await bridge.feePrecision()).to.equal(1000000n);
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "BridgeStarted")
+        .withArgs(user1.address, bridgeAmount, bridgeAmount - standardFee, "ETH", user2.address);
+    });
+
+    it("Should support sub-basis-point fees", async function () {
+      // 1.5 basis points
+      await oracle.updateFeePrecision(1000000n, 150n);
+      const expectedFee = (bridgeAmount * 150n) / 1000000n + OPERATION_FEE;
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "BridgeStarted")
+        .withArgs(user1.address, bridgeAmount, bridgeAmount - expectedFee, "ETH", user2.address);
+    });
+
+    it("Should validate the fee against the max at the new precision", async function () {
+      await expect(oracle.updateFeePrecision(1000000n, 100001n)).to.be.revertedWithCustomError(bridge, "FeeTooHigh");
+      await expect(oracle.updateFeePrecision(15000n, 100n)).to.be.revertedWithCustomError(bridge, "InvalidFeePrecision");
+    });
+  });
+
+  describe("Bridge Ordering", function () {
+    it("Should leave no bridge record when the transfer fails", async function () {
+      await oracle.updateMaxDailyPerUser(ethers.parseEther("1000"));
+      // Allowance covers the amount but the balance does not
+      const bridgeAmount = BRIDGE_AMOUNT + ethers.parseEther("50");
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), bridgeAmount);
+
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.be.revertedWith("ERC20: transfer amount exceeds balance");
+
+      expect(await bridge.outboundNonce()).to.equal(0n);
+      expect(await bridge.userDailyVolume(user1.address)).to.equal(0n);
+      expect(await tokenManager.balanceOf(user1.address)).to.equal(BRIDGE_AMOUNT);
+    });
+  });
+
+  describe("Recipient Lifetime Cap", function () {
+    it("Should reject a mint that takes a recipient past their lifetime cap", async function () {
+      await oracle.updateMaxLifetimePerRecipient(ethers.parseEther("15"));
+
+      await bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("10"));
+      expect(await bridge.lifetimeMinted(user1.address)).to.equal(ethers.parseEther("10"));
+
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("10")))
+        .to.be.revertedWithCustomError(bridge, "RecipientCapExceeded");
+
+      // Other recipients are unaffected
+      await expect(bridge.connect(offchainProcessor).mintAsset(user2.address, ethers.parseEther("10")))
+        .to.emit(bridge, "AssetMinted");
+    });
+
+    it("Should count mints made before the cap was enabled", async function () {
+      await bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("10"));
+      await oracle.updateMaxLifetimePerRecipient(ethers.parseEther("15"));
+
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("10")))
+        .to.be.revertedWithCustomError(bridge, "RecipientCapExceeded");
+    });
+  });
+
+  describe("Permit Bridging", function () {
+    const bridgeAmount = ethers.parseEther("10");
+
+    async function signPermit(signer: SignerWithAddress, nonce: bigint, deadline: bigint) {
+      const domain = {
+        name: "Bridge",
+        version: "1",
+        chainId: (await ethers.provider.getNetwork()).chainId,
+        verifyingContract: await bridge.getAddress()
+      };
+      const types = {
+        BridgePermit: [
+          { name: "user", type: "address" },
+          { name: "amount", type: "uint256" },
+          { name: "destinationChain", type: "string" },
+          { name: "destinationAddress", type: "address" },
+          { name: "nonce", type: "uint256" },
+          { name: "deadline", type: "uint256" }
+        ]
+      };
+      return signer.signTypedData(domain, types, {
+        user: signer.address,
+        amount: bridgeAmount,
+        destinationChain: "ETH",
+        destinationAddress: user2.address,
+        nonce,
+        deadline
+      });
+    }
+
+    beforeEach(async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+    });
+
+    it("Should let a relayer bridge on the user's behalf with a valid permit", async function () {
+      const deadline = BigInt(await time.latest()) + 3600n;
+      const signature = await signPermit(user1, 0n, deadline);
+      const amountAfterFee = bridgeAmount - (bridgeAmount * TRANSFER_FEE) / 10000n - OPERATION_FEE;
+
+      await expect(bridge.connect(user2).receiveAssetWithPermit(
+        user1.address, bridgeAmount, "ETH", user2.address, deadline, signature
+      ))
+        .to.emit(bridge, "BridgeStarted")
+        .withArgs(user1.address, bridgeAmount, amountAfterFee, "ETH", user2.address);
+      expect(await bridge.permitNonces(user1.address)).to.equal(1n);
+      expect(await tokenManager.balanceOf(user1.address)).to.equal(BRIDGE_AMOUNT - bridgeAmount);
+    });
+
+    it("Should not require a native balance from permit users", async function () {
+      await oracle.updateMinUserNativeBalance((await ethers.provider.getBalance(user1.address)) + 1n);
+      const deadline = BigInt(await time.latest()) + 3600n;
+      const signature = await signPermit(user1, 0n, deadline);
+
+      await expect(bridge.connect(user2).receiveAssetWithPermit(
+        user1.address, bridgeAmount, "ETH", user2.address, deadline, signature
+      )).to.emit(bridge, "BridgeStarted");
+    });
+
+    it("Should reject a replayed permit", async function () {
+      const deadline = BigInt(await time.latest()) + 3600n;
+      const signature = await signPermit(user1, 0n, deadline);
+      await bridge.connect(user2).receiveAssetWithPermit(
+        user1.address, bridgeAmount, "ETH", user2.address, deadline, signature
+      );
+      await expect(bridge.connect(user2).receiveAssetWithPermit(
+        user1.address, bridgeAmount, "ETH", user2.address, deadline, signature
+      )).to.be.revertedWithCustomError(bridge, "InvalidPermitSignature");
+    });
+
+    it("Should reject a permit signed by someone else", async function () {
+      const deadline = BigInt(await time.latest()) + 3600n;
+      const signature = await signPermit(user2, 0n, deadline);
+      await expect(bridge.connect(user2).receiveAssetWithPermit(
+        user1.address, bridgeAmount, "ETH", user2.address, deadline, signature
+      )).to.be.revertedWithCustomError(bridge, "InvalidPermitSignature");
+    });
+
+    it("Should reject an expired permit", async function () {
+      const deadline = BigInt(await time.latest()) - 1n;
+      const signature = await signPermit(user1, 0n, deadline);
+      await expect(bridge.connect(user2).receiveAssetWithPermit(
+        user1.address, bridgeAmount, "ETH", user2.address, deadline, signature
+      )).to.be.revertedWithCustomError(bridge, "PermitExpired");
+    });
+  });
+
+  describe("Fee Change Delay", function () {
+    const bridgeAmount = ethers.parseEther("10");
+    const DELAY = 60 * 60;
+
+    beforeEach(async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await oracle.updateFeeChangeDelay(DELAY);
+    });
+
+    it("Should not apply a just-changed fee until the delay elapses", async function () {
+      const newFee = 200n;
+      await expect(oracle.updateTransferFee(newFee)).to.emit(bridge, "TransferFeeScheduled");
+      expect(await bridge.currentTransferFee()).to.equal(TRANSFER_FEE);
+
+      const oldFeeAmount = (bridgeAmount * TRANSFER_FEE) / 10000n + OPERATION_FEE;
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "BridgeStarted")
+        .withArgs(user1.address, bridgeAmount, bridgeAmount - oldFeeAmount, "ETH", user2.address);
+
+      await time.increase(DELAY);
+      expect(await bridge.currentTransferFee()).to.equal(newFee);
+
+      const newFeeAmount = (bridgeAmount * newFee) / 10000n + OPERATION_FEE;
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "BridgeStarted")
+        .withArgs(user1.address, bridgeAmount, bridgeAmount - newFeeAmount, "ETH", user2.address);
+    });
+
+    it("Should only report the Oracle fee change once it applies", async function () {
+      await expect(oracle.updateTransferFee(200n)).not.to.emit(oracle, "FeeUpdated");
+      await oracle.updateFeeChangeDelay(0);
+      await expect(oracle.updateTransferFee(300n))
+        .to.emit(oracle, "FeeUpdated")
+        .withArgs(300n, OPERATION_FEE);
+    });
+
+    it("Should report a matured fee in the config and commit it on the next bridge", async function () {
+      const newFee = 200n;
+      await oracle.updateTransferFee(newFee);
+      const scheduledHash = await bridge.configHash();
+      await time.increase(DELAY);
+
+      const maturedHash = await bridge.configHash();
+      expect(maturedHash).to.not.equal(scheduledHash);
+
+      await bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address);
+      expect(await bridge.transferFee()).to.equal(newFee);
+      expect(await bridge.configHash()).to.equal(maturedHash);
+    });
+
+    it("Should not change the rate through a precision update", async function () {
+      await expect(oracle.updateFeePrecision(10000n, 200n)).to.be.revertedWithCustomError(bridge, "FeeChangeMustBeScheduled");
+      await expect(oracle.updateFeePrecision(1000000n, 20000n)).to.be.revertedWithCustomError(bridge, "FeeChangeMustBeScheduled");
+
+      // Restating the same 1% rate at the finer precision is allowed
+      await oracle.updateFeePrecision(1000000n, 10000n);
+      expect(await bridge.currentTransferFee()).to.equal(10000n);
+      expect(await bridge.feePrecision()).to.equal(1000000n);
+    });
+
+    it("Should not change the precision under a scheduled fee", async function () {
+      await oracle.updateTransferFee(200n);
+      await expect(oracle.updateFeePrecision(1000000n, 10000n)).to.be.revertedWithCustomError(bridge, "FeeChangePending");
+      expect(await bridge.pendingTransferFee()).to.equal(200n);
+
+      // Once the scheduled fee has applied the precision can be restated
+      await time.increase(DELAY);
+      await oracle.updateFeePrecision(1000000n, 20000n);
+      expect(await bridge.currentTransferFee()).to.equal(20000n);
+      expect(await bridge.pendingFeeEffectiveAt()).to.equal(0n);
+    });
+
+    it("Should report a scheduled fee that is replaced before it applies", async function () {
+      await oracle.updateTransferFee(200n);
+      const effectiveAt = await bridge.pendingFeeEffectiveAt();
+
+      await expect(oracle.updateTransferFee(300n))
+        .to.emit(bridge, "FeeChangeCancelled")
+        .withArgs(200n, effectiveAt);
+      expect(await bridge.pendingTransferFee()).to.equal(300n);
+
+      // Applying a fee immediately also drops the one still scheduled
+      await oracle.updateFeeChangeDelay(0);
+      await expect(oracle.updateTransferFee(150n))
+        .to.emit(bridge, "FeeChangeCancelled");
+      await time.increase(DELAY);
+      expect(await bridge.currentTransferFee()).to.equal(150n);
+    });
+
+    it("Should apply fee changes immediately when the delay is disabled", async function () {
+      await oracle.updateFeeChangeDelay(0);
+      await oracle.updateTransferFee(200n);
+      expect(await bridge.transferFee()).to.equal(200n);
+      expect(await bridge.currentTransferFee()).to.equal(200n);
+    });
+  });
+
+  describe("Minting Sunset", function () {
+    it("Should require the bridge to be paused", async function () {
+      await expect(oracle.relinquishBridgeMinting()).to.be.revertedWith("Pausable: not paused");
+    });
+
+    it("Should block mintAsset while the DAO keeps control of the token", async function () {
+      await oracle.pauseBridge();
+      await expect(oracle.relinquishBridgeMinting()).to.emit(bridge, "MintingRelinquished");
+      await oracle.unpauseBridge();
+
+      expect(await bridge.mintingRelinquished()).to.equal(true);
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("10")))
+        .to.be.revertedWithCustomError(bridge, "MintingDisabled");
+
+      const mintAmount = ethers.parseEther("10");
+      await tokenManager.mint(user1.address, mintAmount);
+      expect(await tokenManager.balanceOf(user1.address)).to.equal(BRIDGE_AMOUNT + mintAmount);
+    });
+  });
+
+  describe("Per-Chain Fee Recipients", function () {
+    const bridgeAmount = ethers.parseEther("10");
+    const feeAmount = (bridgeAmount * TRANSFER_FEE) / 10000n + OPERATION_FEE;
+    let ethRecipient: SignerWithAddress;
+    let bscRecipient: SignerWithAddress;
+
+    beforeEach(async function () {
+      [, , , , ethRecipient, bscRecipient] = await ethers.getSigners();
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await oracle.setChainFeeRecipient("ETH", ethRecipient.address);
+      await oracle.setChainFeeRecipient("BSC", bscRecipient.address);
+    });
+
+    it("Should accrue fees per chain and withdraw them to distinct recipients", async function () {
+      await bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address);
+      await bridge.connect(user1).receiveAsset(bridgeAmount, "BSC", user2.address);
+      await bridge.connect(user1).receiveAsset(bridgeAmount, "BSC", user2.address);
+
+      const ethKey = ethers.keccak256(ethers.toUtf8Bytes("ETH"));
+      const bscKey = ethers.keccak256(ethers.toUtf8Bytes("BSC"));
+      expect(await bridge.collectedFeesByChain(ethKey)).to.equal(feeAmount);
+      expect(await bridge.collectedFeesByChain(bscKey)).to.equal(feeAmount * 2n);
+
+      await expect(oracle.withdrawChainFees("ETH"))
+        .to.emit(bridge, "ChainFeesWithdrawn")
+        .withArgs("ETH", ethRecipient.address, feeAmount);
+      await oracle.withdrawChainFees("BSC");
+
+      expect(await tokenManager.balanceOf(ethRecipient.address)).to.equal(feeAmount);
+      expect(await tokenManager.balanceOf(bscRecipient.address)).to.equal(feeAmount * 2n);
+      expect(await bridge.earmarkedFees()).to.equal(0n);
+    });
+
+    it("Should keep chain fees out of the shared withdrawal", async function () {
+      await bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address);
+      await bridge.connect(user1).receiveAsset(bridgeAmount, "SOL", user2.address);
+
+      await expect(oracle.withdrawFeesTo(user2.address))
+        .to.emit(bridge, "FeesWithdrawn")
+        .withArgs(user2.address, feeAmount);
+      expect(await tokenManager.balanceOf(await bridge.getAddress())).to.equal(feeAmount);
+    });
+
+    it("Should not route fees for an alias of a chain into the shared pool", async function () {
+      await oracle.setChainAllowed("ETH", true);
+      await oracle.setChainAllowlistEnabled(true);
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "eth", user2.address))
+        .to.be.revertedWithCustomError(bridge, "ChainNotAllowed");
+      expect(await tokenManager.balanceOf(await bridge.getAddress())).to.equal(0n);
+    });
+  });
+
+  describe("Idempotent Mint", function () {
+    it("Should reject a retry with the same idempotency key", async function () {
+      const key = ethers.id("relayer-transfer-1");
+      const mintAmount = ethers.parseEther("10");
+
+      await expect(bridge.connect(offchainProcessor).mintAssetWithKey(user1.address, mintAmount, key))
+        .to.emit(bridge, "MintKeyProcessed")
+        .withArgs(key);
+      expect(await bridge.processedMints(key)).to.equal(true);
+
+      await expect(bridge.connect(offchainProcessor).mintAssetWithKey(user1.address, mintAmount, key))
+        .to.be.revertedWithCustomError(bridge, "MintAlreadyProcessed");
+      expect(await tokenManager.balanceOf(user1.address)).to.equal(BRIDGE_AMOUNT + mintAmount);
+    });
+
+    it("Should only be callable by the processor", async function () {
+      await expect(bridge.connect(user1).mintAssetWithKey(user1.address, 1n, ethers.id("key")))
+        .to.be.revertedWithCustomError(bridge, "NotOffchainProcessor");
+    });
+  });
+
+  describe("Fee Sweep", function () {
+    const bridgeAmount = ethers.parseEther("10");
+    const feeAmount = (bridgeAmount * TRANSFER_FEE) / 10000n + OPERATION_FEE;
+
+    beforeEach(async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await oracle.setFeeSweep(feeAmount * 2n, owner.address);
+    });
+
+    it("Should sweep fees automatically once the threshold is reached", async function () {
+      const ownerBalance = await tokenManager.balanceOf(owner.address);
+
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .not.to.emit(bridge, "FeesWithdrawn");
+      expect(await tokenManager.balanceOf(await bridge.getAddress())).to.equal(feeAmount);
+
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "FeesWithdrawn")
+        .withArgs(owner.address, feeAmount * 2n);
+      expect(await tokenManager.balanceOf(await bridge.getAddress())).to.equal(0n);
+      expect(await tokenManager.balanceOf(owner.address)).to.equal(ownerBalance + feeAmount * 2n);
+    });
+  });
+
+  describe("Recipient Window Cap", function () {
+    const WINDOW = 60 * 60;
+
+    beforeEach(async function () {
+      await oracle.updateRecipientWindowCap(ethers.parseEther("20"), WINDOW);
+    });
+
+    it("Should mint up to the window cap, reject the overage and reset after the window", async function () {
+      await bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("15"));
+      await bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("5"));
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, 1n))
+        .to.be.revertedWithCustomError(bridge, "RecipientWindowCapExceeded");
+
+      await time.increase(WINDOW);
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("20")))
+        .to.emit(bridge, "AssetMinted");
+    });
+  });
+
+  describe("Initialization Guards", function () {
+    it("Should reject re-initializing the bridge on the Oracle with a clear error", async function () {
+      await expect(oracle.setBridge(user2.address)).to.be.revertedWith("Bridge already set");
+    });
+
+    it("Should reject re-initializing the bridge on the token with a clear error", async function () {
+      await expect(tokenManager.setBridgeAndOracle(user2.address, user2.address))
+        .to.be.revertedWith("Bridge already set");
+    });
+  });
+
+  describe("Remote Decimals", function () {
+    const bridgeAmount = ethers.parseEther("10");
+    const amountAfterFee = bridgeAmount - (bridgeAmount * TRANSFER_FEE) / 10000n - OPERATION_FEE;
+
+    beforeEach(async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await oracle.setChainRemoteDecimals("ETH", 18);
+      await oracle.setChainRemoteDecimals("TRON", 6);
+    });
+
+    it("Should emit the amount scaled to each chain's decimals", async function () {
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "BridgeAmountScaled")
+        .withArgs(user1.address, "ETH", amountAfterFee, 18);
+
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "TRON", user2.address))
+        .to.emit(bridge, "BridgeAmountScaled")
+        .withArgs(user1.address, "TRON", amountAfterFee / 10n ** 12n, 6);
+    });
+
+    it("Should not emit a scaled amount for unconfigured chains", async function () {
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "BSC", user2.address))
+        .not.to.emit(bridge, "BridgeAmountScaled");
+    });
+  });
+
+  describe("Kill Switch", function () {
+    beforeEach(async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address);
+      await expect(oracle.tripBridgeKillSwitch()).to.emit(bridge, "KillSwitchTripped");
+    });
+
+    it("Should block receiveAsset, mintAsset and withdrawFees once tripped", async function () {
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "KillSwitchActive");
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("10")))
+        .to.be.revertedWithCustomError(bridge, "KillSwitchActive");
+      await expect(oracle.withdrawFeesTo(user2.address))
+        .to.be.revertedWithCustomError(bridge, "KillSwitchActive");
+    });
+
+    it("Should be reported by validateBridge", async function () {
+      expect(await bridge.connect(user1).validateBridge.staticCall(ethers.parseEther("10"), "ETH", user2.address))
+        .to.equal(await bridge.REJECT_KILLED());
+    });
+
+    it("Should report the pause first when the bridge is also paused", async function () {
+      await oracle.pauseBridge();
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.be.revertedWith("Pausable: paused");
+      expect(await bridge.connect(user1).validateBridge.staticCall(ethers.parseEther("10"), "ETH", user2.address))
+        .to.equal(await bridge.REJECT_PAUSED());
+    });
+
+    it("Should not be cleared by unpausing", async function () {
+      await oracle.pauseBridge();
+      await oracle.unpauseBridge();
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("10")))
+        .to.be.revertedWithCustomError(bridge, "KillSwitchActive");
+    });
+
+    it("Should only re-enable through the reset path", async function () {
+      await oracle.proposeBridgeKillSwitchReset();
+      await time.increase(await bridge.killSwitchResetDelay());
+      await expect(oracle.resetBridgeKillSwitch()).to.emit(bridge, "KillSwitchReset");
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("10")))
+        .to.emit(bridge, "AssetMinted");
+    });
+  });
+
+  describe("Fee-On-Transfer Tokens", function () {
+    it("Should burn based on the amount actually received", async function () {
+      const TokenFactory = await ethers.getContractFactory("MockFeeOnTransferToken");
+      const token = await TokenFactory.deploy("Taxed", "TAX", 100n); // 1% transfer tax
+      const BridgeFactory = await ethers.getContractFactory("Bridge", { libraries: bridgeLibraries });
+      const taxedBridge = await BridgeFactory.deploy(
+        await token.getAddress(),
+        TRANSFER_FEE,
+        OPERATION_FEE,
+        owner.address,
+        offchainProcessor.address
+      );
+
+      const bridgeAmount = ethers.parseEther("100");
+      await token.mint(user1.address, bridgeAmount);
+      await token.connect(user1).approve(await taxedBridge.getAddress(), bridgeAmount);
+
+      const received = bridgeAmount - bridgeAmount / 100n;
+      const totalFee = (bridgeAmount * TRANSFER_FEE) / 10000n + OPERATION_FEE;
+      await expect(taxedBridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(taxedBridge, "BridgeStarted")
+        .withArgs(user1.address, bridgeAmount, received - totalFee, "ETH", user2.address);
+
+      // Only the fee stays behind; the burn never exceeded what arrived
+      expect(await token.balanceOf(await taxedBridge.getAddress())).to.equal(totalFee);
+    });
+  });
+
+  describe("Chain Fee Recipient Rotation", function () {
+    it("Should withdraw to the current recipient after a change, never the stale one", async function () {
+      const [, , , , oldRecipient, newRecipient] = await ethers.getSigners();
+      const bridgeAmount = ethers.parseEther("10");
+      const feeAmount = (bridgeAmount * TRANSFER_FEE) / 10000n + OPERATION_FEE;
+
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await oracle.setChainFeeRecipient("ETH", oldRecipient.address);
+      await bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address);
+
+      await oracle.setChainFeeRecipient("ETH", newRecipient.address);
+      await expect(oracle.withdrawChainFees("ETH"))
+        .to.emit(bridge, "ChainFeesWithdrawn")
+        .withArgs("ETH", newRecipient.address, feeAmount);
+
+      expect(await tokenManager.balanceOf(oldRecipient.address)).to.equal(0n);
+      expect(await tokenManager.balanceOf(newRecipient.address)).to.equal(feeAmount);
+    });
+
+    it("Should not clear a recipient while the chain holds fees", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await oracle.setChainFeeRecipient("ETH", user2.address);
+      await bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address);
+
+      await expect(oracle.setChainFeeRecipient("ETH", ethers.ZeroAddress))
+        .to.be.revertedWithCustomError(bridge, "ChainFeesNotWithdrawn");
+      await expect(oracle.configureChains([
+        { chain: "ETH", feeRecipient: ethers.ZeroAddress, expectedRelaySeconds: 60, remoteGranularity: 0n, minAmount: 0n, remoteDecimals: 0, hasRemoteDecimals: false }
+      ])).to.be.revertedWithCustomError(bridge, "ChainFeesNotWithdrawn");
+
+      await oracle.withdrawChainFees("ETH");
+      await oracle.setChainFeeRecipient("ETH", ethers.ZeroAddress);
+    });
+  });
+
+  describe("Minimum Pause Duration", function () {
+    const MIN_PAUSE = 24 * 60 * 60;
+
+    beforeEach(async function () {
+      await oracle.updateMinPauseDuration(MIN_PAUSE);
+      await oracle.pauseBridge();
+    });
+
+    it("Should reject a sunset action immediately after pausing", async function () {
+      await expect(oracle.relinquishBridgeMinting()).to.be.revertedWithCustomError(bridge, "PauseTooRecent");
+    });
+
+    it("Should allow the sunset action once the bridge has been paused long enough", async function () {
+      await time.increase(MIN_PAUSE);
+      await expect(oracle.relinquishBridgeMinting()).to.emit(bridge, "MintingRelinquished");
+    });
+  });
+
+  describe("Burn Receipts", function () {
+    it("Should emit a receipt matching the actual burn and block", async function () {
+      await oracle.setBurnReceiptsEnabled(true);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      const bridgeAmount = ethers.parseEther("10");
+      const amountAfterFee = bridgeAmount - (bridgeAmount * TRANSFER_FEE) / 10000n - OPERATION_FEE;
+      const supplyBefore = await tokenManager.totalSupply();
+
+      const tx = await bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address);
+      const receipt = await tx.wait();
+      await expect(tx)
+        .to.emit(bridge, "BurnReceipt")
+        .withArgs(1n, amountAfterFee, receipt!.blockNumber, await tokenManager.getAddress(), 0n);
+      expect(await tokenManager.totalSupply()).to.equal(supplyBefore - amountAfterFee);
+    });
+
+    it("Should carry the configured minimum confirmations", async function () {
+      await oracle.setBurnReceiptsEnabled(true);
+      await oracle.updateMinConfirmations(12);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      const bridgeAmount = ethers.parseEther("10");
+      const amountAfterFee = bridgeAmount - (bridgeAmount * TRANSFER_FEE) / 10000n - OPERATION_FEE;
+
+      const tx = await bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address);
+      const receipt = await tx.wait();
+      await expect(tx)
+        .to.emit(bridge, "BurnReceipt")
+        .withArgs(1n, amountAfterFee, receipt!.blockNumber, await tokenManager.getAddress(), 12n);
+    });
+  });
+
+  describe("Remote Supply Tracking", function () {
+    it("Should net a burn and a matching mint to zero", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      const bridgeAmount = ethers.parseEther("10");
+      const amountAfterFee = bridgeAmount - (bridgeAmount * TRANSFER_FEE) / 10000n - OPERATION_FEE;
+
+      await bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address);
+      expect(await bridge.circulatingOnRemote()).to.equal(amountAfterFee);
+
+      await bridge.connect(offchainProcessor).mintAsset(user1.address, amountAfterFee);
+      expect(await bridge.circulatingOnRemote()).to.equal(0n);
+    });
+
+    it("Should go negative when more is minted than was burned here", async function () {
+      await bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("5"));
+      expect(await bridge.circulatingOnRemote()).to.equal(-ethers.parseEther("5"));
+    });
+  });
+
+  describe("Chain Relay Timeout", function () {
+    it("Should store a per-chain expected relay time for clients", async function () {
+      await expect(oracle.setChainRelayTimeout("ETH", 900))
+        .to.emit(bridge, "ChainRelayTimeoutUpdated")
+        .withArgs("ETH", 900);
+      await oracle.setChainRelayTimeout("BSC", 120);
+
+      const ethConfig = await bridge.chainConfigs(ethers.keccak256(ethers.toUtf8Bytes("ETH")));
+      const bscConfig = await bridge.chainConfigs(ethers.keccak256(ethers.toUtf8Bytes("BSC")));
+      expect(ethConfig.expectedRelaySeconds).to.equal(900n);
+      expect(bscConfig.expectedRelaySeconds).to.equal(120n);
+    });
+  });
+
+  describe("Mint Memo", function () {
+    it("Should attach the memo to the mint", async function () {
+      const mintAmount = ethers.parseEther("10");
+      await expect(bridge.connect(offchainProcessor).mintAssetWithMemo(user1.address, mintAmount, "tag-12345"))
+        .to.emit(bridge, "MintMemo")
+        .withArgs(user1.address, mintAmount, "tag-12345")
+        .and.to.emit(bridge, "AssetMinted")
+        .withArgs(user1.address, mintAmount);
+    });
+
+    it("Should skip the memo when empty", async function () {
+      await expect(bridge.connect(offchainProcessor).mintAssetWithMemo(user1.address, ethers.parseEther("10"), ""))
+        .not.to.emit(bridge, "MintMemo");
+    });
+
+    it("Should reject an oversized memo", async function () {
+      await expect(bridge.connect(offchainProcessor).mintAssetWithMemo(user1.address, 1n, "x".repeat(257)))
+        .to.be.revertedWithCustomError(bridge, "MemoTooLong");
+    });
+  });
+
+  describe("Chain Config Cap", function () {
+    beforeEach(async function () {
+      await oracle.updateMaxChainConfigs(2);
+      await oracle.setChainRelayTimeout("ETH", 900);
+      await oracle.setChainRelayTimeout("BSC", 120);
+    });
+
+    it("Should reject configuring a chain past the cap", async function () {
+      expect(await bridge.chainConfigCount()).to.equal(2n);
+      await expect(oracle.setChainRelayTimeout("SOL", 60)).to.be.revertedWithCustomError(bridge, "TooManyChainConfigs");
+
+      // Updating an existing chain does not consume a slot
+      await oracle.setChainRemoteDecimals("ETH", 18);
+      expect(await bridge.chainConfigCount()).to.equal(2n);
+    });
+
+    it("Should free a slot when a chain config is cleared", async function () {
+      await expect(oracle.clearChainConfig("BSC"))
+        .to.emit(bridge, "ChainConfigCleared")
+        .withArgs("BSC");
+      expect(await bridge.chainConfigCount()).to.equal(1n);
+
+      const bscConfig = await bridge.chainConfigs(ethers.keccak256(ethers.toUtf8Bytes("BSC")));
+      expect(bscConfig.registered).to.equal(false);
+      expect(bscConfig.expectedRelaySeconds).to.equal(0n);
+
+      await expect(oracle.setChainRelayTimeout("SOL", 60)).to.emit(bridge, "ChainRelayTimeoutUpdated");
+    });
+
+    it("Should not clear a chain with unwithdrawn fees", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await oracle.setChainFeeRecipient("ETH", user2.address);
+      await bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address);
+      await expect(oracle.clearChainConfig("ETH")).to.be.revertedWithCustomError(bridge, "ChainFeesPending");
+    });
+  });
+
+  describe("Fee Rounding", function () {
+    beforeEach(async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await oracle.setVerboseEvents(true);
+    });
+
+    it("Should report the remainder for a non-divisible amount", async function () {
+      const bridgeAmount = ethers.parseEther("10") + 4321n;
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "FeeRounding")
+        .withArgs(user1.address, (bridgeAmount * TRANSFER_FEE) % 10000n);
+    });
+
+    it("Should not report a remainder when the fee is capped", async function () {
+      await oracle.updateMaxTotalFee(ethers.parseEther("1.05"));
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10") + 4321n, "ETH", user2.address))
+        .not.to.emit(bridge, "FeeRounding");
+    });
+
+    it("Should not report a remainder for fee-exempt users", async function () {
+      await oracle.setFeeExempt(user1.address, true);
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10") + 4321n, "ETH", user2.address))
+        .not.to.emit(bridge, "FeeRounding");
+    });
+
+    it("Should not emit rounding events when verbose events are off", async function () {
+      await oracle.setVerboseEvents(false);
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10") + 4321n, "ETH", user2.address))
+        .not.to.emit(bridge, "FeeRounding");
+    });
+  });
+
+  describe("Receive Result", function () {
+    it("Should return the bridge confirmation to the caller", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      const result = await bridge.connect(user1).receiveAsset.staticCall(BRIDGE_AMOUNT, "ETH", user2.address);
+      const fee = (BRIDGE_AMOUNT * TRANSFER_FEE) / 10000n + OPERATION_FEE;
+
+      expect(result.bridgeId).to.equal((await bridge.outboundNonce()) + 1n);
+      expect(result.fee).to.equal(fee);
+      expect(result.amountAfterFee).to.equal(BRIDGE_AMOUNT - fee);
+    });
+
+    it("Should let a composing contract read every confirmation field", async function () {
+      const CallerFactory = await ethers.getContractFactory("MockBridgeCaller");
+      const caller = await CallerFactory.deploy(await bridge.getAddress());
+      await tokenManager.connect(user1).transfer(await caller.getAddress(), BRIDGE_AMOUNT);
+      const fee = (BRIDGE_AMOUNT * TRANSFER_FEE) / 10000n + OPERATION_FEE;
+
+      const tx = await caller.bridgeTokens(BRIDGE_AMOUNT, "ETH", user2.address);
+      const receipt = await tx.wait();
+
+      expect(await caller.lastBridgeId()).to.equal(await bridge.outboundNonce());
+      expect(await caller.lastFee()).to.equal(fee);
+      expect(await caller.lastAmountAfterFee()).to.equal(BRIDGE_AMOUNT - fee);
+      expect(await caller.lastBlockNumber()).to.equal(BigInt(receipt!.blockNumber));
+    });
+  });
+
+  describe("Withdraw Cooldown", function () {
+    const COOLDOWN = 3600;
+
+    beforeEach(async function () {
+      await oracle.updateWithdrawCooldown(COOLDOWN);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+    });
+
+    it("Should reject a second withdrawal until the cooldown has passed", async function () {
+      const bridgeAmount = ethers.parseEther("10");
+      await bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address);
+      await oracle.withdrawFeesTo(user2.address);
+
+      await bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address);
+      await expect(oracle.withdrawFeesTo(user2.address)).to.be.revertedWithCustomError(bridge, "WithdrawTooFrequent");
+
+      await time.increase(COOLDOWN);
+      await expect(oracle.withdrawFeesTo(user2.address)).to.emit(bridge, "FeesWithdrawn");
+    });
+  });
+
+  describe("Bridge Route", function () {
+    beforeEach(async function () {
+      await oracle.setLocalChainName("BSC");
+    });
+
+    it("Should emit the outbound route with the fee", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      const fee = (BRIDGE_AMOUNT * TRANSFER_FEE) / 10000n + OPERATION_FEE;
+      await expect(bridge.connect(user1).receiveAsset(BRIDGE_AMOUNT, "ETH", user2.address))
+        .to.emit(bridge, "BridgeRoute")
+        .withArgs("BSC", "ETH", BRIDGE_AMOUNT, fee);
+    });
+
+    it("Should emit the inbound route on mint", async function () {
+      const mintAmount = ethers.parseEther("5");
+      await expect(bridge.connect(offchainProcessor).mintAsset(user2.address, mintAmount))
+        .to.emit(bridge, "BridgeRoute")
+        .withArgs("", "BSC", mintAmount, 0n);
+    });
+  });
+
+  describe("Kill Switch Reset Delay", function () {
+    const RESET_DELAY = 86400;
+
+    beforeEach(async function () {
+      await oracle.updateKillSwitchResetDelay(RESET_DELAY);
+      await oracle.tripBridgeKillSwitch();
+    });
+
+    it("Should only reset once the proposed delay has elapsed", async function () {
+      await expect(oracle.resetBridgeKillSwitch()).to.be.revertedWithCustomError(bridge, "ResetNotProposed");
+
+      await expect(oracle.proposeBridgeKillSwitchReset()).to.emit(bridge, "KillSwitchResetProposed");
+      await expect(oracle.resetBridgeKillSwitch()).to.be.revertedWithCustomError(bridge, "ResetDelayNotElapsed");
+
+      await time.increase(RESET_DELAY);
+      await expect(oracle.resetBridgeKillSwitch()).to.emit(bridge, "KillSwitchReset");
+      expect(await bridge.killSwitchTripped()).to.equal(false);
+    });
+
+    it("Should not allow shortening the delay while tripped", async function () {
+      await expect(oracle.updateKillSwitchResetDelay(0)).to.be.revertedWithCustomError(bridge, "KillSwitchActive");
+    });
+  });
+
+  describe("Kill Switch Reset Defaults", function () {
+    it("Should start with a nonzero reset delay", async function () {
+      expect(await bridge.killSwitchResetDelay()).to.equal(86400n);
+    });
+
+    it("Should require a proposal even without a delay", async function () {
+      await oracle.updateKillSwitchResetDelay(0);
+      await oracle.tripBridgeKillSwitch();
+      await expect(oracle.resetBridgeKillSwitch()).to.be.revertedWithCustomError(bridge, "ResetNotProposed");
+
+      await oracle.proposeBridgeKillSwitchReset();
+      await expect(oracle.resetBridgeKillSwitch()).to.emit(bridge, "KillSwitchReset");
+    });
+
+    it("Should reject a delay above the maximum", async function () {
+      await expect(oracle.updateKillSwitchResetDelay(30 * 86400 + 1)).to.be.revertedWithCustomError(bridge, "DelayTooLong");
+      await oracle.updateKillSwitchResetDelay(30 * 86400);
+    });
+  });
+
+  describe("Event Mask", function () {
+    it("Should mute fee updates while mints still emit", async function () {
+      await oracle.setEventMask(await bridge.EVENT_FEE_UPDATED());
+
+      await expect(oracle.updateTransferFee(200n)).not.to.emit(bridge, "FeeUpdated");
+      await expect(oracle.updateOperationFee(ethers.parseEther("2"))).not.to.emit(oracle, "FeeUpdated");
+      expect(await bridge.transferFee()).to.equal(200n);
+      await expect(bridge.connect(offchainProcessor).mintAsset(user1.address, ethers.parseEther("1")))
+        .to.emit(bridge, "AssetMinted");
+    });
+  });
+
+  describe("Max Total Fee", function () {
+    it("Should clamp the fee on a large transfer to the cap", async function () {
+      const cap = ethers.parseEther("1.5");
+      await oracle.updateMaxTotalFee(cap);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+
+      await expect(bridge.connect(user1).receiveAsset(BRIDGE_AMOUNT, "ETH", user2.address))
+        .to.emit(bridge, "BridgeStarted")
+        .withArgs(user1.address, BRIDGE_AMOUNT, BRIDGE_AMOUNT - cap, "ETH", user2.address);
+    });
+
+    it("Should reject a cap below the operation fee", async function () {
+      await expect(oracle.updateMaxTotalFee(OPERATION_FEE - 1n)).to.be.revertedWithCustomError(bridge, "CapBelowOperationFee");
+    });
+  });
+
+  describe("Delegated Bridging", function () {
+    beforeEach(async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+    });
+
+    it("Should let an approved delegate bridge the user's tokens", async function () {
+      const bridgeAmount = ethers.parseEther("10");
+      await bridge.connect(user1).approveBridgeDelegate(owner.address, bridgeAmount);
+
+      await expect(bridge.connect(owner).receiveAssetDelegated(user1.address, bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "DelegatedBridge")
+        .withArgs(user1.address, owner.address, bridgeAmount);
+      expect(await tokenManager.balanceOf(user1.address)).to.equal(BRIDGE_AMOUNT - bridgeAmount);
+      expect(await bridge.bridgeDelegations(user1.address, owner.address)).to.equal(0n);
+    });
+
+    it("Should not require a native balance from the user", async function () {
+      const bridgeAmount = ethers.parseEther("10");
+      await bridge.connect(user1).approveBridgeDelegate(user2.address, bridgeAmount);
+      await oracle.updateMinUserNativeBalance((await ethers.provider.getBalance(user1.address)) + 1n);
+
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "InsufficientNativeBalance");
+      await expect(bridge.connect(user2).receiveAssetDelegated(user1.address, bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "DelegatedBridge");
+    });
+
+    it("Should reject amounts beyond the delegation", async function () {
+      await bridge.connect(user1).approveBridgeDelegate(owner.address, ethers.parseEther("10"));
+      await expect(
+        bridge.connect(owner).receiveAssetDelegated(user1.address, ethers.parseEther("11"), "ETH", user2.address)
+      ).to.be.revertedWithCustomError(bridge, "DelegationExceeded");
+    });
+  });
+
+  describe("Minimum Transfer Fee", function () {
+    it("Should reject a transfer fee below the floor", async function () {
+      await oracle.updateMinFeeBasisPoints(50n);
+      await expect(oracle.updateTransferFee(49n)).to.be.revertedWithCustomError(bridge, "FeeTooLow");
+      await oracle.updateTransferFee(50n);
+      expect(await bridge.transferFee()).to.equal(50n);
+    });
+
+    it("Should not set a floor above the current fee", async function () {
+      await expect(oracle.updateMinFeeBasisPoints(TRANSFER_FEE + 1n)).to.be.revertedWithCustomError(bridge, "FeeTooLow");
+    });
+
+    it("Should not set a floor above a scheduled fee", async function () {
+      await oracle.updateFeeChangeDelay(3600);
+      await oracle.updateTransferFee(20n);
+      await expect(oracle.updateMinFeeBasisPoints(50n)).to.be.revertedWithCustomError(bridge, "ScheduledFeeTooLow");
+    });
+  });
+
+  describe("Chain Pause", function () {
+    it("Should block a paused chain while others keep working", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await expect(oracle.setChainPaused("ETH", true))
+        .to.emit(bridge, "ChainPausedUpdated")
+        .withArgs("ETH", true);
+
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "ChainPaused");
+      expect(await bridge.connect(user1).validateBridge.staticCall(ethers.parseEther("10"), "ETH", user2.address))
+        .to.equal(await bridge.REJECT_CHAIN_PAUSED());
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "BSC", user2.address))
+        .to.emit(bridge, "BridgeStarted");
+
+      await oracle.setChainPaused("ETH", false);
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.emit(bridge, "BridgeStarted");
+    });
+
+    it("Should reject aliases of a paused chain while the chain allowlist is enabled", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await oracle.setChainPaused("ETH", true);
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "eth", user2.address))
+        .to.emit(bridge, "BridgeStarted");
+
+      await oracle.setChainAllowed("ETH", true);
+      await expect(oracle.setChainAllowlistEnabled(true))
+        .to.emit(bridge, "ChainAllowlistToggled")
+        .withArgs(true);
+      for (const alias of ["eth", "Ethereum", "ETH "]) {
+        await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), alias, user2.address))
+          .to.be.revertedWithCustomError(bridge, "ChainNotAllowed");
+        expect(await bridge.connect(user1).validateBridge.staticCall(ethers.parseEther("10"), alias, user2.address))
+          .to.equal(await bridge.REJECT_CHAIN_NOT_ALLOWED());
+      }
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "ChainPaused");
+    });
+
+    it("Should pause an unconfigured chain at the config cap without using a slot", async function () {
+      await oracle.updateMaxChainConfigs(1);
+      await oracle.setChainRelayTimeout("BSC", 120);
+
+      await expect(oracle.setChainPaused("ETH", true)).to.emit(bridge, "ChainPausedUpdated");
+      expect(await bridge.chainConfigCount()).to.equal(1n);
+    });
+
+    it("Should not clear a paused chain's config", async function () {
+      await oracle.setChainRelayTimeout("ETH", 900);
+      await oracle.setChainPaused("ETH", true);
+      await expect(oracle.clearChainConfig("ETH")).to.be.revertedWithCustomError(bridge, "ChainPaused");
+    });
+  });
+
+  describe("Supply Delta", function () {
+    beforeEach(async function () {
+      await oracle.setVerboseEvents(true);
+    });
+
+    it("Should report a negative delta for burns", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      const amountAfterFee = BRIDGE_AMOUNT - (BRIDGE_AMOUNT * TRANSFER_FEE) / 10000n - OPERATION_FEE;
+      await expect(bridge.connect(user1).receiveAsset(BRIDGE_AMOUNT, "ETH", user2.address))
+        .to.emit(bridge, "SupplyDelta")
+        .withArgs(await tokenManager.getAddress(), -amountAfterFee, INITIAL_SUPPLY - amountAfterFee);
+      expect(await tokenManager.totalSupply()).to.equal(INITIAL_SUPPLY - amountAfterFee);
+    });
+
+    it("Should report a positive delta for mints", async function () {
+      const mintAmount = ethers.parseEther("5");
+      await expect(bridge.connect(offchainProcessor).mintAsset(user2.address, mintAmount))
+        .to.emit(bridge, "SupplyDelta")
+        .withArgs(await tokenManager.getAddress(), mintAmount, INITIAL_SUPPLY + mintAmount);
+    });
+  });
+
+  describe("Emergency Snapshot", function () {
+    it("Should pause and emit the snapshot with current balances in one call", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await bridge.connect(user1).receiveAsset(BRIDGE_AMOUNT, "ETH", user2.address);
+      const fee = (BRIDGE_AMOUNT * TRANSFER_FEE) / 10000n + OPERATION_FEE;
+      const amountAfterFee = BRIDGE_AMOUNT - fee;
+
+      const tx = await oracle.emergencyBridgeSnapshot();
+      const block = await ethers.provider.getBlock((await tx.wait())!.blockNumber);
+
+      await expect(tx).to.emit(bridge, "ConfigSnapshot");
+      await expect(tx)
+        .to.emit(bridge, "EmergencySnapshot")
+        .withArgs(block!.timestamp, fee, 0n, INITIAL_SUPPLY - amountAfterFee, amountAfterFee);
+      expect(await bridge.paused()).to.equal(true);
+    });
+  });
+
+  describe("Treasury Allowlist", function () {
+    beforeEach(async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await bridge.connect(user1).receiveAsset(BRIDGE_AMOUNT, "ETH", user2.address);
+      await oracle.setTreasuryAllowlistEnabled(true);
+      await oracle.setApprovedTreasury(user2.address, true);
+    });
+
+    it("Should withdraw to an approved treasury", async function () {
+      await expect(oracle.withdrawFeesTo(user2.address)).to.emit(bridge, "FeesWithdrawn");
+    });
+
+    it("Should reject an unapproved destination", async function () {
+      await expect(oracle.withdrawFeesTo(owner.address)).to.be.revertedWithCustomError(bridge, "UnapprovedDestination");
+    });
+
+    it("Should not sweep fees to an unapproved recipient", async function () {
+      await expect(oracle.setFeeSweep(1n, owner.address)).to.be.revertedWithCustomError(bridge, "UnapprovedDestination");
+
+      // A recipient revoked after being set is skipped instead of paid
+      await oracle.setFeeSweep(1n, user2.address);
+      await oracle.setApprovedTreasury(user2.address, false);
+      const feesBefore = await tokenManager.balanceOf(await bridge.getAddress());
+      await tokenManager.transfer(user1.address, BRIDGE_AMOUNT);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await expect(bridge.connect(user1).receiveAsset(BRIDGE_AMOUNT, "ETH", user2.address))
+        .not.to.emit(bridge, "FeesWithdrawn");
+      expect(await tokenManager.balanceOf(await bridge.getAddress())).to.be.greaterThan(feesBefore);
+    });
+
+    it("Should not pay chain fees to an unapproved recipient", async function () {
+      await expect(oracle.setChainFeeRecipient("BSC", owner.address)).to.be.revertedWithCustomError(bridge, "UnapprovedDestination");
+
+      await oracle.setChainFeeRecipient("BSC", user2.address);
+      await tokenManager.transfer(user1.address, BRIDGE_AMOUNT);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await bridge.connect(user1).receiveAsset(BRIDGE_AMOUNT, "BSC", user2.address);
+      await oracle.setApprovedTreasury(user2.address, false);
+      await expect(oracle.withdrawChainFees("BSC")).to.be.revertedWithCustomError(bridge, "UnapprovedDestination");
+    });
+  });
+
+  describe("Chain Granularity", function () {
+    const GRANULARITY = 10n ** 12n;
+
+    beforeEach(async function () {
+      await oracle.setChainGranularity("ETH", GRANULARITY);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+    });
+
+    it("Should reject an amount after fee that is not a multiple of the granularity", async function () {
+      const bridgeAmount = ethers.parseEther("10") + 1n;
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "NotDivisible");
+      expect(await bridge.connect(user1).validateBridge.staticCall(bridgeAmount, "ETH", user2.address))
+        .to.equal(await bridge.REJECT_NOT_DIVISIBLE());
+    });
+
+    it("Should accept divisible amounts and other chains", async function () {
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.emit(bridge, "BridgeStarted");
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10") + 1n, "BSC", user2.address))
+        .to.emit(bridge, "BridgeStarted");
+    });
+
+    it("Should not let an alias of the chain skip its granularity", async function () {
+      await oracle.setChainAllowed("ETH", true);
+      await oracle.setChainAllowlistEnabled(true);
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10") + 1n, "Ethereum", user2.address))
+        .to.be.revertedWithCustomError(bridge, "ChainNotAllowed");
+    });
+  });
+
+  describe("User Balance After", function () {
+    it("Should emit the user's balance after the transfer when verbose", async function () {
+      await oracle.setVerboseEvents(true);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      const bridgeAmount = ethers.parseEther("10");
+
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "UserBalanceAfter")
+        .withArgs(user1.address, BRIDGE_AMOUNT - bridgeAmount);
+      expect(await tokenManager.balanceOf(user1.address)).to.equal(BRIDGE_AMOUNT - bridgeAmount);
+    });
+
+    it("Should not emit the balance when verbose events are off", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .not.to.emit(bridge, "UserBalanceAfter");
+    });
+  });
+
+  describe("Chain Minimum Amount", function () {
+    it("Should apply a chain minimum above the global one", async function () {
+      await oracle.updateMinBridgedAmount(ethers.parseEther("1"));
+      await oracle.setChainMinAmount("ETH", ethers.parseEther("20"));
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "AmountBelowChainMinimum");
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "BSC", user2.address))
+        .to.emit(bridge, "BridgeStarted");
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("30"), "ETH", user2.address))
+        .to.emit(bridge, "BridgeStarted");
+    });
+
+    it("Should not let an alias of the chain skip its minimum", async function () {
+      await oracle.setChainMinAmount("ETH", ethers.parseEther("20"));
+      await oracle.setChainAllowed("ETH", true);
+      await oracle.setChainAllowlistEnabled(true);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "eth", user2.address))
+        .to.be.revertedWithCustomError(bridge, "ChainNotAllowed");
+    });
+
+    it("Should report the same first failing check as receiveAsset", async function () {
+      await oracle.setChainMinAmount("ETH", ethers.parseEther("20"));
+      await oracle.setChainGranularity("ETH", ethers.parseEther("0.01"));
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+
+      // Below the chain minimum and not divisible: divisibility is checked first
+      const indivisible = ethers.parseEther("10") + 1n;
+      await expect(bridge.connect(user1).receiveAsset(indivisible, "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "NotDivisible");
+      expect(await bridge.connect(user1).validateBridge.staticCall(indivisible, "ETH", user2.address))
+        .to.equal(await bridge.REJECT_NOT_DIVISIBLE());
+
+      // 8.9 tokens remain after fees, a multiple of the granularity but below the chain minimum
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "AmountBelowChainMinimum");
+      expect(await bridge.connect(user1).validateBridge.staticCall(ethers.parseEther("10"), "ETH", user2.address))
+        .to.equal(await bridge.REJECT_BELOW_CHAIN_MINIMUM());
+    });
+  });
+
+  describe("Native Operation Fee", function () {
+    const NATIVE_FEE = ethers.parseEther("0.01");
+
+    beforeEach(async function () {
+      await oracle.setNativeOperationFee(NATIVE_FEE, owner.address);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+    });
+
+    it("Should collect the flat fee in native currency and bridge the rest", async function () {
+      const bridgeAmount = ethers.parseEther("10");
+      const amountAfterFee = bridgeAmount - (bridgeAmount * TRANSFER_FEE) / 10000n;
+
+      const tx = bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address, { value: NATIVE_FEE });
+      await expect(tx)
+        .to.emit(bridge, "BridgeStarted")
+        .withArgs(user1.address, bridgeAmount, amountAfterFee, "ETH", user2.address);
+      await expect(tx).to.changeEtherBalance(bridge, NATIVE_FEE);
+      expect(await bridge.accruedNativeFees()).to.equal(NATIVE_FEE);
+
+      await expect(oracle.withdrawNativeFees())
+        .to.emit(bridge, "NativeFeesWithdrawn")
+        .withArgs(owner.address, NATIVE_FEE);
+      expect(await bridge.accruedNativeFees()).to.equal(0n);
+    });
+
+    it("Should keep bridging when the collector rejects native transfers", async function () {
+      // The token contract has no receive function
+      await oracle.setNativeOperationFee(NATIVE_FEE, await tokenManager.getAddress());
+      await expect(
+        bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address, { value: NATIVE_FEE })
+      ).to.emit(bridge, "BridgeStarted");
+
+      await expect(oracle.withdrawNativeFees()).to.be.revertedWithCustomError(bridge, "NativeFeeTransferFailed");
+      expect(await bridge.accruedNativeFees()).to.equal(NATIVE_FEE);
+
+      await oracle.setNativeOperationFee(NATIVE_FEE, user2.address);
+      await expect(oracle.withdrawNativeFees()).to.changeEtherBalance(user2, NATIVE_FEE);
+    });
+
+    it("Should reject a missing native fee", async function () {
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "IncorrectNativeFee");
+    });
+
+    it("Should check the native fee before pulling tokens", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), 0n);
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "IncorrectNativeFee");
+    });
+
+    it("Should not charge the native fee to exempt users", async function () {
+      await oracle.setFeeExempt(user1.address, true);
+      await expect(
+        bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address, { value: NATIVE_FEE })
+      ).to.be.revertedWithCustomError(bridge, "IncorrectNativeFee");
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.emit(bridge, "FeeExemptBridge");
+    });
+  });
+
+  describe("Daily Volume", function () {
+    beforeEach(async function () {
+      await oracle.setVerboseEvents(true);
+    });
+
+    it("Should summarize the previous window when it rolls over", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      const bridgeAmount = ethers.parseEther("10");
+
+      const first = await bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address);
+      await expect(first).not.to.emit(bridge, "DailyVolume");
+      const windowStart = (await ethers.provider.getBlock((await first.wait())!.blockNumber))!.timestamp;
+      await bridge.connect(user1).receiveAsset(bridgeAmount, "BSC", user2.address);
+
+      await time.increase(86400);
+      await expect(bridge.connect(user1).receiveAsset(bridgeAmount, "ETH", user2.address))
+        .to.emit(bridge, "DailyVolume")
+        .withArgs(windowStart, bridgeAmount * 2n, 2n);
+      expect(await bridge.dailyWindowVolume()).to.equal(bridgeAmount);
+      expect(await bridge.dailyWindowBridgeCount()).to.equal(1n);
+    });
+
+    it("Should not track volume while verbose events are off", async function () {
+      await oracle.setVerboseEvents(false);
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address);
+
+      expect(await bridge.dailyWindowStart()).to.equal(0n);
+      expect(await bridge.dailyWindowBridgeCount()).to.equal(0n);
+    });
+
+    it("Should discard a partial window when verbose events are turned back on", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address);
+      await oracle.setVerboseEvents(false);
+      await oracle.setVerboseEvents(true);
+
+      expect(await bridge.dailyWindowBridgeCount()).to.equal(0n);
+      await time.increase(86400);
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .not.to.emit(bridge, "DailyVolume");
+    });
+  });
+
+  describe("Chain Batch Configuration", function () {
+    it("Should configure three chains in one call", async function () {
+      const chains = ["ETH", "BSC", "SOL"];
+      await oracle.configureChains(
+        chains.map((chain, i) => ({
+          chain,
+          feeRecipient: user2.address,
+          expectedRelaySeconds: 60 * (i + 1),
+          remoteGranularity: 0n,
+          minAmount: ethers.parseEther(String(i + 1)),
+          remoteDecimals: 6,
+          hasRemoteDecimals: true,
+        }))
+      );
+
+      expect(await bridge.chainConfigCount()).to.equal(3n);
+      for (let i = 0; i < chains.length; i++) {
+        const config = await bridge.chainConfigs(ethers.keccak256(ethers.toUtf8Bytes(chains[i])));
+        expect(config.feeRecipient).to.equal(user2.address);
+        expect(config.expectedRelaySeconds).to.equal(BigInt(60 * (i + 1)));
+        expect(config.minAmount).to.equal(ethers.parseEther(String(i + 1)));
+        expect(config.remoteDecimals).to.equal(6n);
+        expect(config.hasRemoteDecimals).to.equal(true);
+      }
+
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "BSC", user2.address))
+        .to.emit(bridge, "BridgeAmountScaled");
+    });
+
+    it("Should reject unsupported remote decimals in a batch", async function () {
+      await expect(oracle.configureChains([
+        { chain: "ETH", feeRecipient: user2.address, expectedRelaySeconds: 60, remoteGranularity: 0n, minAmount: 0n, remoteDecimals: 19, hasRemoteDecimals: true }
+      ])).to.be.revertedWithCustomError(bridge, "UnsupportedTokenDecimals");
+    });
+
+    it("Should revert the whole batch when an entry is invalid", async function () {
+      await expect(
+        oracle.configureChains([
+          { chain: "ETH", feeRecipient: user2.address, expectedRelaySeconds: 60, remoteGranularity: 0n, minAmount: 0n, remoteDecimals: 0, hasRemoteDecimals: false },
+          { chain: "", feeRecipient: user2.address, expectedRelaySeconds: 60, remoteGranularity: 0n, minAmount: 0n, remoteDecimals: 0, hasRemoteDecimals: false },
+        ])
+      ).to.be.revertedWithCustomError(bridge, "InvalidChain");
+      expect(await bridge.chainConfigCount()).to.equal(0n);
+    });
+  });
+
+  describe("Minimum User Native Balance", function () {
+    it("Should reject a user holding too little native currency", async function () {
+      await tokenManager.connect(user1).approve(await bridge.getAddress(), BRIDGE_AMOUNT);
+      const balance = await ethers.provider.getBalance(user1.address);
+      await oracle.updateMinUserNativeBalance(balance + 1n);
+
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.be.revertedWithCustomError(bridge, "InsufficientNativeBalance");
+      expect(await bridge.connect(user1).validateBridge.staticCall(ethers.parseEther("10"), "ETH", user2.address))
+        .to.equal(await bridge.REJECT_LOW_NATIVE_BALANCE());
+
+      await oracle.updateMinUserNativeBalance(ethers.parseEther("1"));
+      await expect(bridge.connect(user1).receiveAsset(ethers.parseEther("10"), "ETH", user2.address))
+        .to.emit(bridge, "BridgeStarted");
+    });
+  });
+
+  describe("Contract Size", function () {
+    // The hardhat network allows unlimited contract size, so check the EIP-170 limit directly
+    for (const name of ["Bridge", "Oracle"]) {
+      it(`Should fit ${name} within the deployable bytecode limit`, async function () {
+        const artifact = await artifacts.readArtifact(name);
+        expect((artifact.deployedBytecode.length - 2) / 2).to.be.at.most(24576);
+      });
+    }
+  });
 }); 
\ No newline at end of file
@@ -34,8 +34,14 @@ describe("TokenManager", function () {
     const OracleFactory = await ethers.getContractFactory("Oracle");
     oracle = await OracleFactory.deploy(owner.address) as Oracle;
     
+    // Deploy the admin library the Bridge links against
+    const BridgeAdminLibFactory = await ethers.getContractFactory("BridgeAdminLib");
+    const bridgeAdminLib = await BridgeAdminLibFactory.deploy();
+
     // Deploy Bridge
-    const BridgeFactory = await ethers.getContractFactory("Bridge");
+    const BridgeFactory = await ethers.getContractFactory("Bridge", {
+      libraries: { BridgeAdminLib: await bridgeAdminLib.getAddress() },
+    });
     bridge = await BridgeFactory.deploy(
       await tokenManager.getAddress(),
       TRANSFER_FEE,
@@ -50,16 +56,6 @@ describe("TokenManager", function () {
     // Set bridge address in Oracle
     await oracle.setBridge(await bridge.getAddress());
 
-    // For Bridge, the owner is Oracle, so we need to impersonate Oracle to accept ownership
-    const oracleSigner = await ethers.getImpersonatedSigner(await oracle.getAddress());
-    await ethers.provider.send("hardhat_setBalance", [
-      oracleSigner.address,
-      "0x1000000000000000000"
-    ]);
-    
-    // Accept ownership for Bridge (required by Ownable2Step)
-    await bridge.connect(oracleSigner).acceptOwnership();
-    
     // Set bridge and oracle in TokenManager
     await tokenManager.setBridgeAndOracle(await bridge.getAddress(), await oracle.getAddress());
     
@@ -122,7 +118,7 @@ describe("TokenManager", function () {
         burnAmount,
         "ethereum",
         user2.address
-      )).to.be.revertedWith("Insufficient allowance");
+      )).to.be.revertedWithCustomError(bridge, "InsufficientAllowance");
 
       // Approve bridge to spend tokens
       await tokenManager.connect(user1).approve(bridge.getAddress(), burnAmount);
@@ -143,7 +139,7 @@ describe("TokenManager", function () {
       
       // Only offchain processor (initially the owner) can mint
       await expect(bridge.connect(user1).mintAsset(user2.address, mintAmount))
-        .to.be.revertedWith("Only Offchain Processor allowed to call this method");
+        .to.be.revertedWithCustomError(bridge, "NotOffchainProcessor");
       
       // Should succeed when called by owner (initial offchain processor)
       await bridge.connect(owner).mintAsset(user2.address, mintAmount);
@@ -159,7 +155,7 @@ describe("TokenManager", function () {
       
       // Now only user1 can mint
       await expect(bridge.connect(owner).mintAsset(user2.address, mintAmount))
-        .to.be.revertedWith("Only Offchain Processor allowed to call this method");
+        .to.be.revertedWithCustomError(bridge, "NotOffchainProcessor");
       
       await bridge.connect(user1).mintAsset(user2.address, mintAmount);
       